@@ -32,12 +32,12 @@ impl App {
                     format!("Clicked at ({}, {})", point.x, point.y);
             }
             Message::SimpleClick => {
-                self.last_click = format!("Simple click");
+                self.last_click = String::from("Simple click");
             }
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         center(
             column![
                 row![
@@ -45,7 +45,7 @@ impl App {
                         "Click me and I'll tell you where!",
                         0x813060
                     ))
-                    .on_press_with(|point| Message::ClickWithPoint(point)),
+                    .on_press_with(Message::ClickWithPoint),
                     mouse_area(block(
                         "Click me and I won't say a word...",
                         0x008189
@@ -34,3 +34,38 @@ where
 {
     pick_list::PickList::new(options, disabled, selected, on_selected)
 }
+
+/// Pick lists of borrowed string slices, which produce an owned [`String`]
+/// only for the selected option.
+///
+/// Options are never cloned into owned strings: the list works on the
+/// borrowed `&str` directly. Options of type [`Cow<str>`] are also cheap to
+/// clone while borrowed and can be used with [`pick_list()`] directly.
+///
+/// [`Cow<str>`]: std::borrow::Cow
+pub fn pick_list_str<'a, Message, Theme, Renderer>(
+    options: &'a [&'a str],
+    disabled: Option<impl Fn(&[&'a str]) -> Vec<bool> + 'a>,
+    selected: Option<&'a str>,
+    on_selected: impl Fn(String) -> Message + 'a,
+) -> pick_list::PickList<
+    'a,
+    &'a str,
+    &'a [&'a str],
+    &'a str,
+    Message,
+    Theme,
+    Renderer,
+>
+where
+    Message: Clone,
+    Theme: pick_list::Catalog + overlay::menu::Catalog,
+    Renderer: text::Renderer,
+{
+    pick_list::PickList::new(
+        options,
+        disabled,
+        selected,
+        move |option: &str| on_selected(option.to_owned()),
+    )
+}
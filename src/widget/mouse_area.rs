@@ -22,11 +22,12 @@
 // COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
-use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::widget::{tree, Id, Operation, Tree, Widget};
 use iced::advanced::{
     layout, mouse, overlay, renderer, Clipboard, Layout, Shell,
 };
 use iced::event::{self, Event};
+use iced::time::{Duration, Instant};
 use iced::touch;
 use iced::{Element, Length, Point, Rectangle, Size, Vector};
 
@@ -39,17 +40,27 @@ pub struct MouseArea<
     Renderer = iced::Renderer,
 > {
     content: Element<'a, Message, Theme, Renderer>,
+    id: Option<Id>,
     on_press: Option<OnPress<'a, Message>>,
+    on_hold: Option<Message>,
+    hold_threshold: Duration,
     on_release: Option<Message>,
+    on_press_cancel: Option<Message>,
+    on_background_press: Option<Message>,
+    on_touch_press: Option<Message>,
+    on_touch_release: Option<Message>,
     on_double_click: Option<Message>,
     on_right_press: Option<Message>,
     on_right_release: Option<Message>,
     on_middle_press: Option<Message>,
     on_middle_release: Option<Message>,
     on_scroll: Option<Box<dyn Fn(mouse::ScrollDelta) -> Message + 'a>>,
+    on_scroll_accumulated: Option<Box<dyn Fn(f32) -> Message + 'a>>,
     on_enter: Option<Message>,
     on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_exit: Option<Message>,
+    on_exit_with_duration: Option<Box<dyn Fn(Duration) -> Message + 'a>>,
+    on_bounds_change: Option<Box<dyn Fn(Rectangle) -> Message + 'a>>,
     interaction: Option<mouse::Interaction>,
 }
 
@@ -68,6 +79,13 @@ impl<'a, Message: Clone> OnPress<'a, Message> {
 }
 
 impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
+    /// Sets the [`Id`] of the [`MouseArea`].
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the message to emit on a left button press.
     #[must_use]
     pub fn on_press(mut self, message: Message) -> Self {
@@ -101,6 +119,33 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit when a left button press or a touch held for at
+    /// least the [`hold_threshold`] is released.
+    ///
+    /// When set, [`on_press`] is deferred until the release and only emitted
+    /// for shorter presses, so a quick tap and a deliberate hold can be told
+    /// apart. Neither is emitted if the press moves by more than
+    /// [`HOLD_MOVE_THRESHOLD`] before its release.
+    ///
+    /// [`hold_threshold`]: Self::hold_threshold
+    /// [`on_press`]: Self::on_press
+    #[must_use]
+    pub fn on_hold(mut self, message: Message) -> Self {
+        self.on_hold = Some(message);
+        self
+    }
+
+    /// Sets how long a press must be held to emit [`on_hold`] instead of
+    /// [`on_press`]. It is [`HOLD_THRESHOLD`] by default.
+    ///
+    /// [`on_hold`]: Self::on_hold
+    /// [`on_press`]: Self::on_press
+    #[must_use]
+    pub fn hold_threshold(mut self, threshold: Duration) -> Self {
+        self.hold_threshold = threshold;
+        self
+    }
+
     /// The message to emit on a left button release.
     #[must_use]
     pub fn on_release(mut self, message: Message) -> Self {
@@ -108,6 +153,66 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit when a left button press or a touch that began
+    /// inside the [`MouseArea`] is released outside of it.
+    ///
+    /// This tells apart an interaction cancelled by dragging off the area,
+    /// which does not trigger [`on_release`]. A touch that is lost, e.g.
+    /// because the window lost focus, is cancelled as well, wherever it was.
+    ///
+    /// [`on_release`]: Self::on_release
+    #[must_use]
+    pub fn on_press_cancel(mut self, message: Message) -> Self {
+        self.on_press_cancel = Some(message);
+        self
+    }
+
+    /// The message to emit on a left button press or a touch that the
+    /// content of the [`MouseArea`] did not capture, e.g. a press on the
+    /// empty background around its interactive widgets.
+    ///
+    /// It is emitted along with [`on_press`], if any. Touches emit
+    /// [`on_touch_press`] instead, when it is set.
+    ///
+    /// [`on_press`]: Self::on_press
+    /// [`on_touch_press`]: Self::on_touch_press
+    #[must_use]
+    pub fn on_background_press(mut self, message: Message) -> Self {
+        self.on_background_press = Some(message);
+        self
+    }
+
+    /// The message to emit when a finger is pressed.
+    ///
+    /// When set, touches no longer trigger [`on_press`],
+    /// [`on_background_press`] nor [`on_double_click`], so they can be told
+    /// apart from the mouse and a touch emits a single press message. A
+    /// held touch still triggers [`on_hold`].
+    ///
+    /// [`on_press`]: Self::on_press
+    /// [`on_background_press`]: Self::on_background_press
+    /// [`on_double_click`]: Self::on_double_click
+    /// [`on_hold`]: Self::on_hold
+    #[must_use]
+    pub fn on_touch_press(mut self, message: Message) -> Self {
+        self.on_touch_press = Some(message);
+        self
+    }
+
+    /// The message to emit when a finger is lifted.
+    ///
+    /// When set, touches no longer trigger [`on_release`], so they can be
+    /// told apart from the mouse. It is emitted after [`on_hold`], if the
+    /// touch was held.
+    ///
+    /// [`on_release`]: Self::on_release
+    /// [`on_hold`]: Self::on_hold
+    #[must_use]
+    pub fn on_touch_release(mut self, message: Message) -> Self {
+        self.on_touch_release = Some(message);
+        self
+    }
+
     /// The message to emit on a double click.
     ///
     /// If you use this with [`on_press`]/[`on_release`], those
@@ -162,6 +267,21 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit with the running total of the vertical scroll
+    /// deltas received by the area.
+    ///
+    /// Deltas are accumulated in pixels; [`mouse::ScrollDelta::Lines`] are
+    /// converted using [`PIXELS_PER_LINE`], the same factor used by `iced`'s
+    /// `Scrollable`. Scrolling down decreases the total.
+    #[must_use]
+    pub fn on_scroll_accumulated(
+        mut self,
+        on_scroll_accumulated: impl Fn(f32) -> Message + 'a,
+    ) -> Self {
+        self.on_scroll_accumulated = Some(Box::new(on_scroll_accumulated));
+        self
+    }
+
     /// The message to emit when the mouse enters the area.
     #[must_use]
     pub fn on_enter(mut self, message: Message) -> Self {
@@ -183,6 +303,37 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit with how long the mouse stayed in the area, when
+    /// it exits the area.
+    ///
+    /// The duration is measured from the matching enter. Only hover changes
+    /// matter: if the area is laid out again while hovered, the measurement
+    /// carries on, unless the new bounds move the area away from the mouse,
+    /// which counts as an exit.
+    #[must_use]
+    pub fn on_exit_with_duration(
+        mut self,
+        on_exit_with_duration: impl Fn(Duration) -> Message + 'a,
+    ) -> Self {
+        self.on_exit_with_duration = Some(Box::new(on_exit_with_duration));
+        self
+    }
+
+    /// The message to emit when the bounds of the area change.
+    ///
+    /// The [`Rectangle`] is given in absolute layout coordinates, i.e.
+    /// relative to the window and without the translation applied by any
+    /// scrollable ancestors. Changes are detected while processing events, so
+    /// the first message is emitted on the first event the area receives.
+    #[must_use]
+    pub fn on_bounds_change(
+        mut self,
+        on_bounds_change: impl Fn(Rectangle) -> Message + 'a,
+    ) -> Self {
+        self.on_bounds_change = Some(Box::new(on_bounds_change));
+        self
+    }
+
     /// The [`mouse::Interaction`] to use when hovering the area.
     #[must_use]
     pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
@@ -195,11 +346,68 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
 #[derive(Default)]
 struct State {
     is_hovered: bool,
+    hovered_at: Option<Instant>,
     bounds: Rectangle,
     cursor_position: Option<Point>,
     previous_click: Option<mouse::Click>,
+    scroll_accumulation: f32,
+    is_pressed: bool,
+    held_press: Option<(Instant, Point)>,
+}
+
+impl State {
+    /// Clears any gesture being tracked.
+    fn reset(&mut self) {
+        self.previous_click = None;
+        self.scroll_accumulation = 0.0;
+        self.is_pressed = false;
+        self.held_press = None;
+    }
 }
 
+/// Produces an [`Operation`] that resets the gestures tracked by the
+/// [`MouseArea`] with the given [`Id`].
+///
+/// This clears any pending double click and the accumulated scroll, which
+/// avoids stale gestures once the interface changes underneath the area.
+pub fn reset<T>(id: Id) -> impl Operation<T> {
+    struct Reset {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Reset {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                if let Some(state) = state.downcast_mut::<State>() {
+                    state.reset();
+                }
+            }
+        }
+    }
+
+    Reset { target: id }
+}
+
+/// The amount of pixels a [`mouse::ScrollDelta::Lines`] unit is worth when
+/// accumulating scroll deltas.
+pub const PIXELS_PER_LINE: f32 = 60.0;
+
+/// The default time a press must be held to count as a hold.
+pub const HOLD_THRESHOLD: Duration = Duration::from_millis(500);
+
+/// How far a press can move before its release emits neither a press nor a
+/// hold.
+pub const HOLD_MOVE_THRESHOLD: f32 = 10.0;
+
 impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
     /// Creates a [`MouseArea`] with the given content.
     pub fn new(
@@ -207,17 +415,27 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
     ) -> Self {
         MouseArea {
             content: content.into(),
+            id: None,
             on_press: None,
+            on_hold: None,
+            hold_threshold: HOLD_THRESHOLD,
             on_release: None,
+            on_press_cancel: None,
+            on_background_press: None,
+            on_touch_press: None,
+            on_touch_release: None,
             on_double_click: None,
             on_right_press: None,
             on_right_release: None,
             on_middle_press: None,
             on_middle_release: None,
             on_scroll: None,
+            on_scroll_accumulated: None,
             on_enter: None,
             on_move: None,
             on_exit: None,
+            on_exit_with_duration: None,
+            on_bounds_change: None,
             interaction: None,
         }
     }
@@ -267,6 +485,8 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        operation.custom(tree.state.downcast_mut::<State>(), self.id.as_ref());
+
         self.content.as_widget().operate(
             &mut tree.children[0],
             layout,
@@ -296,6 +516,18 @@ where
             shell,
             viewport,
         ) {
+            // A press captured by the content is not a press of our own
+            if let Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            ))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            {
+                let state: &mut State = tree.state.downcast_mut();
+
+                state.is_pressed = false;
+                state.held_press = None;
+            }
+
             return event::Status::Captured;
         }
 
@@ -397,10 +629,27 @@ fn update<Message: Clone, Theme, Renderer>(
     if state.cursor_position != cursor_position || state.bounds != bounds {
         let was_hovered = state.is_hovered;
 
+        if state.bounds != bounds {
+            if let Some(on_bounds_change) = widget.on_bounds_change.as_ref() {
+                shell.publish(on_bounds_change(bounds));
+            }
+        }
+
         state.is_hovered = cursor.is_over(layout.bounds());
         state.cursor_position = cursor_position;
         state.bounds = bounds;
 
+        if state.is_hovered && !was_hovered {
+            state.hovered_at = Some(Instant::now());
+        } else if !state.is_hovered && was_hovered {
+            if let (Some(on_exit_with_duration), Some(hovered_at)) = (
+                widget.on_exit_with_duration.as_ref(),
+                state.hovered_at.take(),
+            ) {
+                shell.publish(on_exit_with_duration(hovered_at.elapsed()));
+            }
+        }
+
         match (
             widget.on_enter.as_ref(),
             widget.on_move.as_ref(),
@@ -421,16 +670,63 @@ fn update<Message: Clone, Theme, Renderer>(
         }
     }
 
+    if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    | Event::Touch(
+        touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+    ) = event
+    {
+        let was_pressed = std::mem::take(&mut state.is_pressed);
+        let is_lost =
+            matches!(event, Event::Touch(touch::Event::FingerLost { .. }));
+
+        // A lost touch is cancelled wherever it was last seen
+        if was_pressed && (is_lost || !cursor.is_over(layout.bounds())) {
+            state.held_press = None;
+
+            if let Some(message) = widget.on_press_cancel.as_ref() {
+                shell.publish(message.clone());
+            }
+        }
+    }
+
     if !cursor.is_over(layout.bounds()) {
         return event::Status::Ignored;
     }
 
+    // Touches are told apart from the mouse when a touch-specific message
+    // is set, replacing the press or release messages
+    let is_touch = matches!(event, Event::Touch(_));
+    let on_touch_press = widget.on_touch_press.as_ref().filter(|_| is_touch);
+    let on_press = widget
+        .on_press
+        .as_ref()
+        .filter(|_| on_touch_press.is_none());
+    let on_background_press = widget
+        .on_background_press
+        .as_ref()
+        .filter(|_| on_touch_press.is_none());
+
     if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
     | Event::Touch(touch::Event::FingerPressed { .. }) = event
     {
-        let mut captured = false;
+        state.is_pressed = true;
 
-        if let Some(on_press) = widget.on_press.as_ref() {
+        // Presses captured by the content never reach this point
+        if let Some(message) = on_background_press {
+            shell.publish(message.clone());
+        }
+
+        // A published background press still keeps the press to ourselves
+        let mut captured = on_background_press.is_some();
+
+        if widget.on_hold.is_some() {
+            captured = true;
+
+            // The press is told apart from a hold on release
+            state.held_press = cursor
+                .position_in(layout.bounds())
+                .map(|position| (Instant::now(), position));
+        } else if let Some(on_press) = on_press {
             captured = true;
 
             if let Some(position) = cursor.position_in(layout.bounds()) {
@@ -439,7 +735,9 @@ fn update<Message: Clone, Theme, Renderer>(
             }
         }
 
-        if let Some(position) = cursor_position {
+        if let Some(position) =
+            cursor_position.filter(|_| on_touch_press.is_none())
+        {
             if let Some(message) = widget.on_double_click.as_ref() {
                 let new_click = mouse::Click::new(
                     position,
@@ -459,17 +757,54 @@ fn update<Message: Clone, Theme, Renderer>(
             }
         }
 
+        if let Some(message) = on_touch_press {
+            shell.publish(message.clone());
+
+            captured = true;
+        }
+
         if captured {
             return event::Status::Captured;
         }
     }
 
-    if let Some(message) = widget.on_release.as_ref() {
-        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerLifted { .. }) = event
+    if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    | Event::Touch(touch::Event::FingerLifted { .. }) = event
+    {
+        let mut captured = false;
+
+        if let (Some(on_hold), Some((pressed_at, origin))) =
+            (widget.on_hold.as_ref(), state.held_press.take())
         {
+            captured = true;
+
+            let is_moved =
+                !cursor.position_in(layout.bounds()).is_some_and(|position| {
+                    position.distance(origin) <= HOLD_MOVE_THRESHOLD
+                });
+
+            if !is_moved {
+                if pressed_at.elapsed() >= widget.hold_threshold {
+                    shell.publish(on_hold.clone());
+                } else if let Some(on_press) = on_press {
+                    shell.publish(on_press.get(origin));
+                }
+            }
+        }
+
+        let on_release = widget
+            .on_touch_release
+            .as_ref()
+            .filter(|_| is_touch)
+            .or(widget.on_release.as_ref());
+
+        if let Some(message) = on_release {
             shell.publish(message.clone());
 
+            captured = true;
+        }
+
+        if captured {
             return event::Status::Captured;
         }
     }
@@ -517,13 +852,247 @@ fn update<Message: Clone, Theme, Renderer>(
         }
     }
 
-    if let Some(on_scroll) = widget.on_scroll.as_ref() {
-        if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+    if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+        let mut captured = false;
+
+        if let Some(on_scroll) = widget.on_scroll.as_ref() {
             shell.publish(on_scroll(delta));
 
+            captured = true;
+        }
+
+        if let Some(on_scroll_accumulated) =
+            widget.on_scroll_accumulated.as_ref()
+        {
+            state.scroll_accumulation += match delta {
+                mouse::ScrollDelta::Lines { y, .. } => y * PIXELS_PER_LINE,
+                mouse::ScrollDelta::Pixels { y, .. } => y,
+            };
+
+            shell.publish(on_scroll_accumulated(state.scroll_accumulation));
+
+            captured = true;
+        }
+
+        if captured {
             return event::Status::Captured;
         }
     }
 
     event::Status::Ignored
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+    use iced::widget::Space;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Press,
+        Hold,
+        TouchPress,
+        TouchRelease,
+        Cancel,
+        Background,
+    }
+
+    type TestMouseArea<'a> = MouseArea<'a, Message, iced::Theme, ()>;
+
+    /// The bounds the [`MouseArea`] under test is laid out in.
+    const BOUNDS: Size = Size::new(100.0, 100.0);
+
+    fn mouse_area<'a>() -> TestMouseArea<'a> {
+        MouseArea::new(Space::new(Length::Fill, Length::Fill))
+    }
+
+    /// Lays out the [`MouseArea`] and sends it an event with the cursor at
+    /// the given position, returning its status and the produced messages.
+    fn send(
+        mouse_area: &mut TestMouseArea<'_>,
+        tree: &mut Tree,
+        event: Event,
+        position: Point,
+    ) -> (event::Status, Vec<Message>) {
+        let node = mouse_area.layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, BOUNDS),
+        );
+        let mut messages = Vec::new();
+
+        let status = mouse_area.on_event(
+            tree,
+            event,
+            Layout::new(&node),
+            mouse::Cursor::Available(position),
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(BOUNDS),
+        );
+
+        (status, messages)
+    }
+
+    const INSIDE: Point = Point::new(50.0, 50.0);
+
+    fn finger_pressed() -> Event {
+        Event::Touch(touch::Event::FingerPressed {
+            id: touch::Finger(0),
+            position: INSIDE,
+        })
+    }
+
+    fn finger_lifted() -> Event {
+        Event::Touch(touch::Event::FingerLifted {
+            id: touch::Finger(0),
+            position: INSIDE,
+        })
+    }
+
+    /// A widget capturing the left button presses over it.
+    struct CapturesPresses;
+
+    impl Widget<Message, iced::Theme, ()> for CapturesPresses {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fill, Length::Fill)
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(limits.max())
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &iced::Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+
+        fn on_event(
+            &mut self,
+            _tree: &mut Tree,
+            event: Event,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+            _renderer: &(),
+            _clipboard: &mut dyn Clipboard,
+            _shell: &mut Shell<'_, Message>,
+            _viewport: &Rectangle,
+        ) -> event::Status {
+            if cursor.is_over(layout.bounds())
+                && event
+                    == Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left,
+                    ))
+            {
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        }
+    }
+
+    #[test]
+    fn lost_touch_is_cancelled_even_inside_the_area() {
+        let mut mouse_area = mouse_area().on_press_cancel(Message::Cancel);
+        let mut tree = Tree::new(&mouse_area as &dyn Widget<_, _, _>);
+
+        let _ = send(&mut mouse_area, &mut tree, finger_pressed(), INSIDE);
+
+        let (_, messages) = send(
+            &mut mouse_area,
+            &mut tree,
+            Event::Touch(touch::Event::FingerLost {
+                id: touch::Finger(0),
+                position: INSIDE,
+            }),
+            INSIDE,
+        );
+        assert_eq!(messages, vec![Message::Cancel]);
+    }
+
+    #[test]
+    fn press_captured_by_the_content_is_not_cancelled() {
+        let mut mouse_area = MouseArea::new(
+            iced::widget::Row::new()
+                .push(Space::new(Length::Fill, Length::Fill))
+                .push(Element::new(CapturesPresses)),
+        )
+        .on_press_cancel(Message::Cancel);
+        let mut tree = Tree::new(&mouse_area as &dyn Widget<_, _, _>);
+
+        let press =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let release =
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+
+        // The background is pressed first, then the content
+        let _ = send(
+            &mut mouse_area,
+            &mut tree,
+            press.clone(),
+            Point::new(25.0, 50.0),
+        );
+        let (status, _) =
+            send(&mut mouse_area, &mut tree, press, Point::new(75.0, 50.0));
+        assert_eq!(status, event::Status::Captured);
+
+        let (_, messages) =
+            send(&mut mouse_area, &mut tree, release, Point::new(150.0, 50.0));
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn touch_press_replaces_the_background_press() {
+        let mut mouse_area = mouse_area()
+            .on_background_press(Message::Background)
+            .on_touch_press(Message::TouchPress);
+        let mut tree = Tree::new(&mouse_area as &dyn Widget<_, _, _>);
+
+        let (_, messages) =
+            send(&mut mouse_area, &mut tree, finger_pressed(), INSIDE);
+        assert_eq!(messages, vec![Message::TouchPress]);
+
+        let (_, messages) = send(
+            &mut mouse_area,
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            INSIDE,
+        );
+        assert_eq!(messages, vec![Message::Background]);
+    }
+
+    #[test]
+    fn held_touch_triggers_hold_along_with_the_touch_messages() {
+        let mut mouse_area = mouse_area()
+            .on_press(Message::Press)
+            .on_hold(Message::Hold)
+            .hold_threshold(Duration::ZERO)
+            .on_touch_press(Message::TouchPress)
+            .on_touch_release(Message::TouchRelease);
+        let mut tree = Tree::new(&mouse_area as &dyn Widget<_, _, _>);
+
+        let (status, messages) =
+            send(&mut mouse_area, &mut tree, finger_pressed(), INSIDE);
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages, vec![Message::TouchPress]);
+
+        let (status, messages) =
+            send(&mut mouse_area, &mut tree, finger_lifted(), INSIDE);
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages, vec![Message::Hold, Message::TouchRelease]);
+    }
+}
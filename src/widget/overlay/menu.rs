@@ -22,22 +22,27 @@
 // COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
-use iced::advanced::text::{self, Text};
-use iced::advanced::widget::Tree;
+use iced::advanced::text::{self, Paragraph as _, Text};
+use iced::advanced::widget::{self, operation, Operation, Tree};
 use iced::advanced::{layout, mouse, overlay, renderer, Clipboard, Layout};
 use iced::advanced::{Shell, Widget};
 use iced::alignment;
 use iced::border::{self, Border};
 use iced::event::{self, Event};
+use iced::time::{Duration, Instant};
 use iced::touch;
-use iced::widget::scrollable::{self, Scrollable};
+use iced::widget::scrollable::{self, AbsoluteOffset, Scrollable};
+use iced::window;
 use iced::{
     Background, Color, Element, Length, Padding, Pixels, Point, Rectangle,
-    Size, Theme, Vector,
+    Shadow, Size, Theme, Vector,
 };
 
+use crate::widget::mouse_area::PIXELS_PER_LINE;
+
 /// A list of selectable options.
 #[allow(missing_debug_implementations)]
+#[allow(clippy::type_complexity)]
 pub struct Menu<
     'a,
     'b,
@@ -53,14 +58,49 @@ pub struct Menu<
     state: &'a mut State,
     options: &'a [T],
     disabled: Option<Vec<bool>>,
+    dividers: Option<Vec<Option<String>>>,
     hovered_option: &'a mut Option<usize>,
-    on_selected: Box<dyn FnMut(T) -> Message + 'a>,
+    on_selected: Box<dyn FnMut(T, SelectSource) -> Option<Message> + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    on_disabled_hover: Option<&'a dyn Fn(T) -> Message>,
+    on_option_double_click: Option<&'a dyn Fn(T) -> Message>,
+    on_cursor: Option<&'a dyn Fn(Point) -> Message>,
+    description: Option<&'a dyn Fn(&T) -> Option<String>>,
+    shortcut: Option<&'a dyn Fn(&T) -> Option<String>>,
+    rich_label: Option<&'a dyn Fn(&T) -> Vec<(String, Color)>>,
+    indicator: Option<&'a dyn Fn(&T) -> Option<Color>>,
+    tooltip: Option<&'a dyn Fn(&T) -> Option<String>>,
+    option_font: Option<&'a dyn Fn(&T) -> Option<Renderer::Font>>,
+    row_busy: Option<&'a dyn Fn(&T) -> bool>,
+    disabled_style: Option<&'a dyn Fn(&T) -> Option<DisabledStyle>>,
+    on_scroll: Option<&'a dyn Fn(f32) -> Message>,
+    on_flip: Option<&'a dyn Fn(bool) -> Message>,
+    on_scroll_start: Option<Message>,
+    pinned_option: Option<usize>,
+    header: Option<&'a str>,
+    sticky_header: bool,
+    close_button: bool,
+    close_button_label: &'a str,
+    no_results_message: Option<&'a str>,
     width: f32,
+    trap_scroll: bool,
+    resizable: bool,
+    snap_rows: bool,
+    overscroll: bool,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
+    text_wrapping: Option<text::Wrapping>,
+    orientation: Orientation,
+    columns: usize,
+    reverse: bool,
+    scroll: bool,
+    scrollbar: ScrollbarPolicy,
+    border_radius: Option<border::Radius>,
+    border: Option<Box<dyn Fn(&Theme) -> Border + 'a>>,
+    alignment: alignment::Horizontal,
+    target_width: f32,
     font: Option<Renderer::Font>,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
@@ -80,7 +120,7 @@ where
         state: &'a mut State,
         options: &'a [T],
         hovered_option: &'a mut Option<usize>,
-        on_selected: impl FnMut(T) -> Message + 'a,
+        mut on_selected: impl FnMut(T) -> Message + 'a,
         disabled: Option<Vec<bool>>,
         on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
         class: &'a <Theme as Catalog>::Class<'b>,
@@ -89,14 +129,51 @@ where
             state,
             options,
             disabled,
+            dividers: None,
             hovered_option,
-            on_selected: Box::new(on_selected),
+            on_selected: Box::new(move |option, _source| {
+                Some(on_selected(option))
+            }),
             on_option_hovered,
+            on_disabled_hover: None,
+            on_option_double_click: None,
+            on_cursor: None,
+            description: None,
+            shortcut: None,
+            rich_label: None,
+            indicator: None,
+            tooltip: None,
+            option_font: None,
+            row_busy: None,
+            disabled_style: None,
+            on_scroll: None,
+            on_flip: None,
+            on_scroll_start: None,
+            pinned_option: None,
+            header: None,
+            sticky_header: false,
+            close_button: false,
+            close_button_label: DEFAULT_CLOSE_BUTTON_LABEL,
+            no_results_message: None,
             width: 0.0,
+            trap_scroll: false,
+            resizable: false,
+            snap_rows: false,
+            overscroll: false,
             padding: Padding::ZERO,
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
+            text_wrapping: None,
+            orientation: Orientation::default(),
+            columns: 1,
+            reverse: false,
+            scroll: true,
+            border_radius: None,
+            border: None,
+            alignment: alignment::Horizontal::Left,
+            target_width: 0.0,
+            scrollbar: ScrollbarPolicy::default(),
             font: None,
             class,
         }
@@ -108,12 +185,72 @@ where
         self
     }
 
+    /// Sets the horizontal alignment of the [`Menu`] relative to its target.
+    ///
+    /// The [`Menu`] is left-aligned with its target by default.
+    pub fn alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the width of the target of the [`Menu`], which it is aligned
+    /// against.
+    pub fn target_width(mut self, target_width: f32) -> Self {
+        self.target_width = target_width;
+        self
+    }
+
+    /// Sets whether the [`Menu`] captures all the wheel events over it, even
+    /// when its options cannot be scrolled any further.
+    pub fn trap_scroll(mut self, trap_scroll: bool) -> Self {
+        self.trap_scroll = trap_scroll;
+        self
+    }
+
+    /// Sets whether the [`Menu`] can be widened by dragging its right edge.
+    ///
+    /// The chosen width is kept in the [`State`] of the [`Menu`], so it
+    /// survives until the [`State`] is dropped.
+    ///
+    /// The edge takes precedence over the part of a vertical scrollbar
+    /// beneath it.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Sets whether a vertical [`Menu`] snaps to the nearest option once it
+    /// stops scrolling, so that no option is cut off at its top.
+    ///
+    /// The end of the options is a snapping point too.
+    pub fn snap_rows(mut self, snap_rows: bool) -> Self {
+        self.snap_rows = snap_rows;
+        self
+    }
+
+    /// Sets whether a vertical [`Menu`] bounces when its options are scrolled
+    /// past their start or end with the mouse wheel.
+    pub fn overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`Menu`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
         self
     }
 
+    /// Applies the dense preset to the [`Menu`], matching the rows of a
+    /// dense [`PickList`].
+    ///
+    /// [`PickList`]: crate::widget::pick_list::PickList
+    pub fn dense(self) -> Self {
+        self.padding(DENSE_PADDING)
+            .text_size(DENSE_TEXT_SIZE)
+            .text_line_height(DENSE_LINE_HEIGHT)
+    }
+
     /// Sets the text size of the [`Menu`].
     pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
         self.text_size = Some(text_size.into());
@@ -135,12 +272,339 @@ where
         self
     }
 
+    /// Sets the [`text::Wrapping`] strategy of the options of the [`Menu`].
+    ///
+    /// Unless set, every option is laid out on a single line. When set, the
+    /// height of each option grows to fit its wrapped label.
+    pub fn text_wrapping(mut self, wrapping: text::Wrapping) -> Self {
+        self.text_wrapping = Some(wrapping);
+        self
+    }
+
+    /// Sets the [`Orientation`] of the options of the [`Menu`].
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the number of columns of the grid the options of a vertical
+    /// [`Menu`] are laid out in.
+    ///
+    /// The options fill the grid row by row. A single column is used by
+    /// default.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Sets whether the options of the [`Menu`] are displayed in reverse
+    /// order, last one first.
+    ///
+    /// Options keep their indices, so that selections and hovers still refer
+    /// to the original options.
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Sets the radius of the corners of the [`Menu`] and of its rows,
+    /// overriding the one of its [`Style`].
+    pub fn border_radius(
+        mut self,
+        border_radius: Option<border::Radius>,
+    ) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+
+    /// Sets the closure producing the [`Border`] of the [`Menu`] from the
+    /// current theme, overriding the width and color of the one of its
+    /// [`Style`].
+    ///
+    /// This lets the [`Menu`] match the border of the widget that opened it.
+    pub fn border(mut self, border: impl Fn(&Theme) -> Border + 'a) -> Self {
+        self.border = Some(Box::new(border));
+        self
+    }
+
+    /// Sets whether the options of the [`Menu`] can be scrolled.
+    ///
+    /// A [`Menu`] that cannot be scrolled is as large as its content, even
+    /// if it does not fit in the space available.
+    pub fn scroll(mut self, scroll: bool) -> Self {
+        self.scroll = scroll;
+        self
+    }
+
+    /// Sets the message to produce when an option is selected, given the
+    /// [`SelectSource`] of the selection.
+    ///
+    /// It replaces the message given to [`Menu::new`]. No message is
+    /// produced when it returns `None`.
+    pub fn on_selected_with(
+        mut self,
+        on_selected: impl FnMut(T, SelectSource) -> Option<Message> + 'a,
+    ) -> Self {
+        self.on_selected = Box::new(on_selected);
+        self
+    }
+
+    /// Sets the [`ScrollbarPolicy`] of the [`Menu`].
+    pub fn scrollbar(mut self, scrollbar: ScrollbarPolicy) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
     /// Sets the font of the [`Menu`].
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
         self.font = Some(font.into());
         self
     }
 
+    /// Sets the closure producing the description of each option.
+    ///
+    /// Options with a description are drawn with a smaller, dimmer second
+    /// line under their label.
+    #[allow(clippy::type_complexity)]
+    pub fn description(
+        mut self,
+        description: Option<&'a dyn Fn(&T) -> Option<String>>,
+    ) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// Sets the closure producing the shortcut hint of each option.
+    ///
+    /// Shortcut hints are drawn right-aligned in their row, using the
+    /// `shortcut_color` of the [`Style`].
+    #[allow(clippy::type_complexity)]
+    pub fn shortcut(
+        mut self,
+        shortcut: Option<&'a dyn Fn(&T) -> Option<String>>,
+    ) -> Self {
+        self.shortcut = shortcut;
+        self
+    }
+
+    /// Sets the closure producing the styled segments of the label of each
+    /// option.
+    ///
+    /// The segments are drawn one after the other and wrapped like plain
+    /// labels, each in its own [`Color`], except for disabled and selected
+    /// options, which use the text color of their status. An empty list of
+    /// segments falls back to the plain label of the option.
+    #[allow(clippy::type_complexity)]
+    pub fn rich_label(
+        mut self,
+        rich_label: Option<&'a dyn Fn(&T) -> Vec<(String, Color)>>,
+    ) -> Self {
+        self.rich_label = rich_label;
+        self
+    }
+
+    /// Sets the closure producing the color of the status indicator of each
+    /// option.
+    ///
+    /// Indicators are drawn as a small dot at the right edge of their row,
+    /// after the shortcut hint. Space is reserved for them in every row, so
+    /// that they never overlap the text.
+    #[allow(clippy::type_complexity)]
+    pub fn indicator(
+        mut self,
+        indicator: Option<&'a dyn Fn(&T) -> Option<Color>>,
+    ) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Sets the closure producing the tooltip of each option.
+    ///
+    /// The tooltip of the hovered option is shown below its row after a
+    /// short delay, within the bounds of the [`Menu`].
+    #[allow(clippy::type_complexity)]
+    pub fn tooltip(
+        mut self,
+        tooltip: Option<&'a dyn Fn(&T) -> Option<String>>,
+    ) -> Self {
+        self.tooltip = tooltip;
+        self
+    }
+
+    /// Sets the closure producing the font of the label of each option.
+    ///
+    /// Labels are drawn in the font of the [`Menu`] when it returns `None`.
+    #[allow(clippy::type_complexity)]
+    pub fn option_font(
+        mut self,
+        option_font: Option<&'a dyn Fn(&T) -> Option<Renderer::Font>>,
+    ) -> Self {
+        self.option_font = option_font;
+        self
+    }
+
+    /// Sets the closure deciding which options are busy.
+    ///
+    /// Busy options show an animated spinner in place of their status
+    /// indicator. The [`Menu`] is redrawn continuously while any option is
+    /// busy.
+    pub fn row_busy(
+        mut self,
+        row_busy: Option<&'a dyn Fn(&T) -> bool>,
+    ) -> Self {
+        self.row_busy = row_busy;
+        self
+    }
+
+    /// Sets the closure producing the [`DisabledStyle`] of each disabled
+    /// option.
+    ///
+    /// Disabled options are drawn with the disabled colors of the [`Style`]
+    /// when it returns `None`.
+    #[allow(clippy::type_complexity)]
+    pub fn disabled_style(
+        mut self,
+        disabled_style: Option<&'a dyn Fn(&T) -> Option<DisabledStyle>>,
+    ) -> Self {
+        self.disabled_style = disabled_style;
+        self
+    }
+
+    /// Sets the dividers of the [`Menu`], one entry per option.
+    ///
+    /// A divider is drawn above every option with a `Some` entry, with its
+    /// text centered on the line unless it is empty. Dividers are not drawn
+    /// in a grid of multiple columns, and their text is not drawn in a
+    /// horizontal [`Menu`].
+    pub fn dividers(mut self, dividers: Option<Vec<Option<String>>>) -> Self {
+        self.dividers = dividers;
+        self
+    }
+
+    /// Sets the index of an option to pin at the top of the [`Menu`], above
+    /// a divider, in addition to its regular position.
+    pub fn pinned_option(mut self, pinned_option: Option<usize>) -> Self {
+        self.pinned_option = pinned_option;
+        self
+    }
+
+    /// Sets the message to produce when the cursor moves over a disabled
+    /// option.
+    ///
+    /// Disabled options can be hovered but not selected. The message is
+    /// produced once per disabled option the cursor enters.
+    pub fn on_disabled_hover(
+        mut self,
+        on_disabled_hover: Option<&'a dyn Fn(T) -> Message>,
+    ) -> Self {
+        self.on_disabled_hover = on_disabled_hover;
+        self
+    }
+
+    /// Sets the message to produce when an option is double clicked.
+    ///
+    /// When set, a single click only hovers an option. A double click
+    /// selects it, as a single click otherwise would, and then produces
+    /// this message.
+    pub fn on_option_double_click(
+        mut self,
+        on_option_double_click: Option<&'a dyn Fn(T) -> Message>,
+    ) -> Self {
+        self.on_option_double_click = on_option_double_click;
+        self
+    }
+
+    /// Sets the message to produce with the position of the cursor, relative
+    /// to the options of the [`Menu`], as it moves over them.
+    ///
+    /// Positions are reported at most once per [`CURSOR_REPORT_INTERVAL`];
+    /// the last one is always reported.
+    pub fn on_cursor(
+        mut self,
+        on_cursor: Option<&'a dyn Fn(Point) -> Message>,
+    ) -> Self {
+        self.on_cursor = on_cursor;
+        self
+    }
+
+    /// Sets a static title displayed as the first row of the [`Menu`].
+    ///
+    /// The header cannot be hovered nor selected.
+    pub fn header(mut self, header: Option<&'a str>) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets whether the header of a vertical [`Menu`] sticks to the top of
+    /// the viewport while the options are scrolled.
+    pub fn sticky_header(mut self, sticky_header: bool) -> Self {
+        self.sticky_header = sticky_header;
+        self
+    }
+
+    /// Sets whether a close button is displayed as the last row of the
+    /// [`Menu`].
+    ///
+    /// Presses on the close button are left unhandled by the [`Menu`], so
+    /// that its owner closes it just like after a press outside of it.
+    pub fn close_button(mut self, close_button: bool) -> Self {
+        self.close_button = close_button;
+        self
+    }
+
+    /// Sets the label of the close button of the [`Menu`].
+    ///
+    /// It is [`DEFAULT_CLOSE_BUTTON_LABEL`] by default.
+    pub fn close_button_label(mut self, label: &'a str) -> Self {
+        self.close_button_label = label;
+        self
+    }
+
+    /// Sets the message displayed in place of the options of the [`Menu`]
+    /// when there are none.
+    ///
+    /// The message cannot be hovered nor selected.
+    pub fn no_results_message(
+        mut self,
+        no_results_message: Option<&'a str>,
+    ) -> Self {
+        self.no_results_message = no_results_message;
+        self
+    }
+
+    /// Sets the message to produce with the vertical scroll offset of the
+    /// [`Menu`] whenever it changes.
+    pub fn on_scroll(
+        mut self,
+        on_scroll: Option<&'a dyn Fn(f32) -> Message>,
+    ) -> Self {
+        self.on_scroll = on_scroll;
+        self
+    }
+
+    /// Sets the message to produce when the [`Menu`] is scrolled back to the
+    /// start of its options.
+    ///
+    /// It is produced once every time the start is reached, not while the
+    /// [`Menu`] stays there, nor when it opens there.
+    pub fn on_scroll_start(mut self, on_scroll_start: Option<Message>) -> Self {
+        self.on_scroll_start = on_scroll_start;
+        self
+    }
+
+    /// Sets the message to produce once the placement of the [`Menu`] is
+    /// known, given whether it opened upward.
+    ///
+    /// The message is produced once per open, unless the placement changes
+    /// while the [`Menu`] is open.
+    pub fn on_flip(
+        mut self,
+        on_flip: Option<&'a dyn Fn(bool) -> Message>,
+    ) -> Self {
+        self.on_flip = on_flip;
+        self
+    }
+
     /// Turns the [`Menu`] into an overlay [`Element`] at the given target
     /// position.
     ///
@@ -158,12 +622,48 @@ where
             target_height,
         )))
     }
+
+    /// Measures the height of the [`Menu`] with all of its options visible,
+    /// excluding its border.
+    ///
+    /// The [`Menu`] must not be open, since it is laid out to be measured.
+    pub fn content_height(self, renderer: &Renderer) -> f32 {
+        let width = self.width;
+        let overlay = Overlay::new(Point::ORIGIN, self, 0.0);
+
+        let _ = overlay.list.as_widget().layout(
+            overlay.state,
+            renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY))
+                .width(width),
+        );
+
+        let height = overlay.rows().height();
+        *overlay.content_height = Some(height);
+
+        height
+    }
 }
 
 /// The local state of a [`Menu`].
 #[derive(Debug)]
 pub struct State {
     tree: Tree,
+    pressed_option: Option<Press>,
+    hovered_disabled: Option<usize>,
+    hover_locked_until: Option<Instant>,
+    pending_scroll: Option<Scroll>,
+    opened_upward: Option<bool>,
+    hover_tooltip: Option<Tooltip>,
+    spinner: Option<Spinner>,
+    cursor_report: CursorReport,
+    is_at_scroll_start: Option<bool>,
+    previous_click: Option<(usize, mouse::Click)>,
+    is_keyboard_navigated: bool,
+    resize: Resize,
+    scrolled_at: Option<Instant>,
+    bounce: Option<Bounce>,
+    content_height: Option<f32>,
 }
 
 impl State {
@@ -171,10 +671,264 @@ impl State {
     pub fn new() -> Self {
         Self {
             tree: Tree::empty(),
+            pressed_option: None,
+            hovered_disabled: None,
+            hover_locked_until: None,
+            pending_scroll: None,
+            opened_upward: None,
+            hover_tooltip: None,
+            spinner: None,
+            cursor_report: CursorReport::default(),
+            is_at_scroll_start: None,
+            previous_click: None,
+            is_keyboard_navigated: false,
+            resize: Resize::default(),
+            scrolled_at: None,
+            bounce: None,
+            content_height: None,
         }
     }
+
+    /// Forgets the last reported placement and scroll position of the
+    /// [`Menu`], so that they are reported again the next time it opens.
+    ///
+    /// Any drag of its edge or scroll animation left over from the last time
+    /// it was open ends.
+    pub fn forget_placement(&mut self) {
+        self.opened_upward = None;
+        self.is_at_scroll_start = None;
+        self.resize.drag = None;
+        self.scrolled_at = None;
+        self.bounce = None;
+    }
+
+    /// Returns the height of the [`Menu`] with all of its options visible,
+    /// excluding its border, as of the last time it was laid out or
+    /// measured.
+    pub fn content_height(&self) -> Option<f32> {
+        self.content_height
+    }
+
+    /// Forgets the last height of the options of the [`Menu`], e.g. because
+    /// they may have changed, so that it is measured again when needed.
+    pub fn forget_content_height(&mut self) {
+        self.content_height = None;
+    }
+
+    /// Hides the tooltip of the hovered option of the [`Menu`], if any, until
+    /// the mouse hovers an option again.
+    pub fn hide_tooltip(&mut self) {
+        self.hover_tooltip = None;
+    }
+
+    /// Scrolls the [`Menu`] to the option at the given index the next time
+    /// it is laid out.
+    pub fn scroll_to(&mut self, index: usize) {
+        self.pending_scroll = Some(Scroll::To(index));
+    }
+
+    /// Scrolls the [`Menu`] just enough for the option at the given index to
+    /// be fully visible the next time it is laid out.
+    ///
+    /// Nothing happens if the option is already visible.
+    pub fn scroll_into_view(&mut self, index: usize) {
+        self.pending_scroll = Some(Scroll::IntoView(index));
+    }
+
+    /// Prevents the mouse from changing the hovered option of the [`Menu`]
+    /// until the given [`Instant`].
+    pub fn lock_hover(&mut self, until: Instant) {
+        self.hover_locked_until = Some(until);
+    }
+
+    /// Returns whether an option of the [`Menu`] is being touched or has been
+    /// tapped and is waiting to be selected.
+    pub fn is_pressed(&self) -> bool {
+        self.pressed_option.is_some()
+    }
+
+    /// Sets whether the hovered option of the [`Menu`] was last changed with
+    /// the keyboard, which outlines it with the `keyboard_highlight_border`
+    /// of the [`Style`], if any.
+    ///
+    /// Moving the mouse over the options unsets it.
+    pub fn set_keyboard_navigation(&mut self, is_keyboard: bool) {
+        self.is_keyboard_navigated = is_keyboard;
+    }
+}
+
+/// A scroll to an option of a [`Menu`] waiting for its next layout.
+#[derive(Debug, Clone, Copy)]
+enum Scroll {
+    /// Scroll the option to the start of the list.
+    To(usize),
+    /// Scroll the option into view, as little as possible.
+    IntoView(usize),
+}
+
+/// An option of a [`Menu`] being touched.
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    option: usize,
+    at: Instant,
+    origin: Point,
+    is_released: bool,
+}
+
+/// The tooltip of an option of a [`Menu`] hovered by the mouse.
+#[derive(Debug, Clone, Copy)]
+struct Tooltip {
+    option: usize,
+    at: Instant,
+    is_shown: bool,
+}
+
+/// The animation of the spinner of the busy options of a [`Menu`].
+#[derive(Debug, Clone, Copy)]
+struct Spinner {
+    started_at: Instant,
+    elapsed: Duration,
+}
+
+/// The reports of the position of the cursor over a [`Menu`].
+#[derive(Debug, Clone, Copy, Default)]
+struct CursorReport {
+    reported_at: Option<Instant>,
+    pending: Option<Point>,
+}
+
+/// The rows of a [`List`], placed once per layout and kept in its [`Tree`].
+#[derive(Default)]
+struct Rows<P: text::Paragraph> {
+    /// The bounds of every row, relative to the list and in display order.
+    placements: Vec<(Row, Rectangle)>,
+    /// The height of the label of every option, measured once per layout.
+    label_heights: Vec<f32>,
+    /// The styled label of every option with rich segments, shaped once per
+    /// layout in the colors of its segments unless the option is disabled.
+    rich_labels: Vec<Option<P>>,
+}
+
+impl<P: text::Paragraph> Rows<P> {
+    /// Get the height of the rows, from the top of the first one to the
+    /// bottom of the last one
+    fn height(&self) -> f32 {
+        self.placements
+            .iter()
+            .fold(0.0, |height: f32, (_, bounds)| {
+                height.max(bounds.y + bounds.height)
+            })
+    }
+
+    /// Get the rows overlapping the span from `start` to `end` along the
+    /// given [`Orientation`], relative to the list
+    fn within(
+        &self,
+        orientation: Orientation,
+        start: f32,
+        end: f32,
+    ) -> impl Iterator<Item = &(Row, Rectangle)> {
+        let span = move |bounds: &Rectangle| match orientation {
+            Orientation::Vertical => (bounds.y, bounds.y + bounds.height),
+            Orientation::Horizontal => (bounds.x, bounds.x + bounds.width),
+        };
+
+        // Rows are in display order, so their spans never go backwards
+        let first = self
+            .placements
+            .partition_point(|(_, bounds)| span(bounds).1 <= start);
+
+        self.placements[first..]
+            .iter()
+            .take_while(move |(_, bounds)| span(bounds).0 <= end)
+    }
+
+    /// Get the rows at the given position, relative to the list
+    fn at(
+        &self,
+        orientation: Orientation,
+        position: Point,
+    ) -> impl Iterator<Item = &(Row, Rectangle)> {
+        let offset = match orientation {
+            Orientation::Vertical => position.y,
+            Orientation::Horizontal => position.x,
+        };
+
+        self.within(orientation, offset, offset)
+            .filter(move |(_, bounds)| bounds.contains(position))
+    }
+}
+
+/// The width of a [`Menu`] chosen by dragging its right edge.
+#[derive(Debug, Clone, Copy, Default)]
+struct Resize {
+    width: Option<f32>,
+    drag: Option<Drag>,
+}
+
+/// A drag of the right edge of a [`Menu`].
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: f32,
+    width: f32,
+}
+
+/// The width of the zone at the right edge of a [`Menu`] that can be
+/// dragged to resize it.
+const RESIZE_ZONE_WIDTH: f32 = 6.0;
+
+/// How long a [`Menu`] must stop scrolling before it snaps to the nearest
+/// option.
+const SNAP_DELAY: Duration = Duration::from_millis(150);
+
+/// The elastic offset of the options of a [`Menu`] scrolled past their start
+/// or end.
+#[derive(Debug, Clone, Copy)]
+struct Bounce {
+    offset: f32,
+    ticked_at: Instant,
 }
 
+/// The fraction of a scroll past the start or end of the options that moves
+/// them.
+const OVERSCROLL_RESISTANCE: f32 = 0.3;
+
+/// The maximum distance the options can be moved past their start or end.
+const OVERSCROLL_MAX: f32 = 24.0;
+
+/// How fast the options spring back after a bounce, per second.
+const OVERSCROLL_DECAY: f32 = 12.0;
+
+/// The minimum time between two reports of the position of the cursor over a
+/// [`Menu`].
+pub const CURSOR_REPORT_INTERVAL: Duration = Duration::from_millis(16);
+
+/// The amount of dots of the spinner of a busy option.
+const SPINNER_DOTS: usize = 8;
+
+/// The diameter of a dot of the spinner of a busy option.
+const SPINNER_DOT_SIZE: f32 = 2.0;
+
+/// How long the spinner of a busy option takes to complete a turn.
+const SPINNER_PERIOD: Duration = Duration::from_millis(800);
+
+/// The distance from the start of the options within which a [`Menu`] is
+/// considered scrolled to the start.
+const SCROLL_START_THRESHOLD: f32 = 4.0;
+
+/// How long an option must be hovered before its tooltip is shown.
+const TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+/// The padding around the text of the tooltip of an option.
+const TOOLTIP_PADDING: f32 = 4.0;
+
+/// How long a tapped option shows its pressed feedback before it is selected.
+const PRESSED_FEEDBACK: Duration = Duration::from_millis(100);
+
+/// How far a finger can move before a touch is considered a scroll instead of
+/// a tap.
+const TAP_THRESHOLD: f32 = 10.0;
+
 impl Default for State {
     fn default() -> Self {
         Self::new()
@@ -191,22 +945,685 @@ where
     /// Calculate the index of an option based on a cursor position within the list bounds
     fn option_index_at(
         &self,
+        rows: &Rows<Renderer::Paragraph>,
         cursor_position: Point,
-        renderer: &Renderer,
     ) -> Option<usize> {
-        let text_size =
-            self.text_size.unwrap_or_else(|| renderer.default_size());
-        let option_height =
-            f32::from(self.text_line_height.to_absolute(text_size))
-                + self.padding.vertical();
-
-        let index = (cursor_position.y / option_height) as usize;
+        rows.at(self.orientation, cursor_position)
+            .find_map(|(row, _)| row.option())
+    }
 
-        if index < self.options.len() {
-            Some(index)
-        } else {
-            None
-        }
+    /// Check if the close button is at a cursor position within the list
+    /// bounds
+    fn is_close_button_at(
+        &self,
+        rows: &Rows<Renderer::Paragraph>,
+        cursor_position: Point,
+    ) -> bool {
+        self.close_button
+            && rows
+                .at(self.orientation, cursor_position)
+                .any(|(row, _)| *row == Row::Close)
+    }
+
+    /// Calculate the bounds of the header when it sticks to the top of the
+    /// given viewport, in absolute coordinates
+    fn sticky_header_bounds(
+        &self,
+        rows: &Rows<Renderer::Paragraph>,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Option<Rectangle> {
+        if !self.sticky_header {
+            return None;
+        }
+
+        rows.placements
+            .iter()
+            .find(|(row, _)| *row == Row::Header)
+            .map(|(_, header_bounds)| {
+                let header_bounds =
+                    *header_bounds + Vector::new(bounds.x, bounds.y);
+
+                Rectangle {
+                    y: header_bounds.y.max(viewport.y),
+                    ..header_bounds
+                }
+            })
+    }
+
+    /// Draw the header of the list within the given bounds
+    fn draw_header(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+        header: &str,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let label_height =
+            f32::from(self.text_line_height.to_absolute(text_size));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + style.row_inset,
+                    width: bounds.width - style.row_inset * 2.0,
+                    ..bounds
+                },
+                border: border::rounded(style.border.radius),
+                ..renderer::Quad::default()
+            },
+            style.header_background,
+        );
+
+        renderer.fill_text(
+            Text {
+                content: header.to_owned(),
+                bounds: Size::new(f32::INFINITY, label_height),
+                size: text_size,
+                line_height: self.text_line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: self.text_shaping,
+                wrapping: text::Wrapping::default(),
+            },
+            Point::new(bounds.x + self.padding.left, bounds.center_y()),
+            style.header_text_color,
+            *viewport,
+        );
+    }
+
+    /// Draw a divider with the given text, if not empty, within the bounds of
+    /// its row
+    fn draw_divider(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+        content: &str,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        let thickness = style.border.width.max(1.0);
+
+        if self.orientation == Orientation::Horizontal {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.center_x() - thickness / 2.0,
+                        y: bounds.y + self.padding.top,
+                        width: thickness,
+                        height: bounds.height - self.padding.vertical(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.divider_color,
+            );
+
+            return;
+        }
+
+        let line = |x: f32, width: f32| renderer::Quad {
+            bounds: Rectangle {
+                x,
+                y: bounds.center_y() - thickness / 2.0,
+                width: width.max(0.0),
+                height: thickness,
+            },
+            ..renderer::Quad::default()
+        };
+
+        let left = bounds.x + self.padding.left;
+        let right = bounds.x + bounds.width - self.padding.right;
+
+        if content.is_empty() {
+            renderer.fill_quad(line(left, right - left), style.divider_color);
+
+            return;
+        }
+
+        let paragraph = Renderer::Paragraph::with_text(Text {
+            content,
+            bounds: Size::new(f32::INFINITY, bounds.height),
+            size: description_text_size(
+                self.text_size.unwrap_or_else(|| renderer.default_size()),
+            ),
+            line_height: self.text_line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::None,
+        });
+
+        let half_width = paragraph.min_width() / 2.0 + DIVIDER_LABEL_SPACING;
+        let center_x = (left + right) / 2.0;
+
+        renderer.fill_quad(
+            line(left, center_x - half_width - left),
+            style.divider_color,
+        );
+        renderer.fill_quad(
+            line(center_x + half_width, right - center_x - half_width),
+            style.divider_color,
+        );
+
+        renderer.fill_paragraph(
+            &paragraph,
+            Point::new(center_x, bounds.center_y()),
+            style.divider_text_color,
+            *viewport,
+        );
+    }
+
+    /// Draw the tooltip of the option at the given index below its row,
+    /// or above it when there is no space left in the viewport
+    #[allow(clippy::too_many_arguments)]
+    fn draw_tooltip(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+        rows: &Rows<Renderer::Paragraph>,
+        index: usize,
+        content: &str,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        let Some(row_bounds) = rows
+            .placements
+            .iter()
+            .find(|(row, _)| row.option() == Some(index))
+            .map(|(_, row_bounds)| {
+                *row_bounds + Vector::new(bounds.x, bounds.y)
+            })
+        else {
+            return;
+        };
+
+        let text_size = description_text_size(
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        );
+
+        let paragraph = Renderer::Paragraph::with_text(Text {
+            content,
+            bounds: Size::new(
+                viewport.width - TOOLTIP_PADDING * 2.0,
+                f32::INFINITY,
+            ),
+            size: text_size,
+            line_height: self.text_line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::default(),
+        });
+
+        let size = paragraph
+            .min_bounds()
+            .expand([TOOLTIP_PADDING * 2.0, TOOLTIP_PADDING * 2.0]);
+
+        let x = (row_bounds.x + self.padding.left)
+            .min(viewport.x + viewport.width - size.width)
+            .max(viewport.x);
+
+        let y = if row_bounds.y + row_bounds.height + size.height
+            <= viewport.y + viewport.height
+        {
+            row_bounds.y + row_bounds.height
+        } else {
+            (row_bounds.y - size.height).max(viewport.y)
+        };
+
+        let tooltip_bounds = Rectangle::new(Point::new(x, y), size);
+
+        renderer.with_layer(*viewport, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: tooltip_bounds,
+                    border: border::rounded(style.border.radius),
+                    ..renderer::Quad::default()
+                },
+                style.tooltip_background,
+            );
+
+            renderer.fill_paragraph(
+                &paragraph,
+                Point::new(x + TOOLTIP_PADDING, y + TOOLTIP_PADDING),
+                style.tooltip_text_color,
+                *viewport,
+            );
+        });
+    }
+
+    /// Calculate the bounds of every row of a list of the given size,
+    /// relative to the list and in display order
+    ///
+    /// Options are laid out in a grid when there are multiple columns, below
+    /// any other rows.
+    fn placements(
+        &self,
+        rows: &Rows<Renderer::Paragraph>,
+        size: Size,
+        renderer: &Renderer,
+    ) -> Vec<(Row, Rectangle)> {
+        let mut placements = Vec::new();
+        let mut offset = 0.0;
+
+        if self.orientation == Orientation::Horizontal {
+            for row in self.rows() {
+                let width = self.row_width(rows, row, renderer);
+
+                placements.push((
+                    row,
+                    Rectangle::new(
+                        Point::new(offset, 0.0),
+                        Size::new(width, size.height),
+                    ),
+                ));
+
+                offset += width;
+            }
+
+            return placements;
+        }
+
+        let columns = self.columns.max(1);
+        let mut grid = Vec::new();
+
+        for row in self.rows() {
+            if let (Row::Option(index), true) = (row, columns > 1) {
+                grid.push(index);
+                continue;
+            }
+
+            // The close button stays below the grid
+            if row == Row::Close && columns > 1 {
+                continue;
+            }
+
+            let height = self.row_height(rows, row, renderer);
+
+            placements.push((
+                row,
+                Rectangle::new(
+                    Point::new(0.0, offset),
+                    Size::new(size.width, height),
+                ),
+            ));
+
+            offset += height;
+        }
+
+        let cell_width = size.width / columns as f32;
+
+        for line in grid.chunks(columns) {
+            let height = line.iter().fold(0.0, |height: f32, index| {
+                height.max(self.option_height(rows, *index, renderer))
+            });
+
+            for (column, index) in line.iter().enumerate() {
+                placements.push((
+                    Row::Option(*index),
+                    Rectangle::new(
+                        Point::new(cell_width * column as f32, offset),
+                        Size::new(cell_width, height),
+                    ),
+                ));
+            }
+
+            offset += height;
+        }
+
+        if self.close_button && columns > 1 {
+            placements.push((
+                Row::Close,
+                Rectangle::new(
+                    Point::new(0.0, offset),
+                    Size::new(
+                        size.width,
+                        self.row_height(rows, Row::Close, renderer),
+                    ),
+                ),
+            ));
+        }
+
+        placements
+    }
+
+    /// Calculate the width of a row laid out horizontally, including the
+    /// divider next to a pinned option
+    fn row_width(
+        &self,
+        rows: &Rows<Renderer::Paragraph>,
+        row: Row,
+        renderer: &Renderer,
+    ) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        match row {
+            Row::Empty => {
+                self.text_width(
+                    self.no_results_message.unwrap_or_default(),
+                    text_size,
+                    font,
+                ) + self.padding.horizontal()
+            }
+            Row::Header => {
+                self.text_width(
+                    self.header.unwrap_or_default(),
+                    text_size,
+                    font,
+                ) + self.padding.horizontal()
+            }
+            Row::Close => {
+                self.text_width(self.close_button_label, text_size, font)
+                    + self.padding.horizontal()
+            }
+            Row::Divider(_) => DIVIDER_WIDTH,
+            Row::Option(index) => self.option_width(rows, index, renderer),
+            Row::Pinned(index) => {
+                self.option_width(rows, index, renderer) + PINNED_DIVIDER_WIDTH
+            }
+        }
+    }
+
+    /// Calculate the width of the option at the given index when laid out
+    /// horizontally, including its shortcut and description, if any
+    fn option_width(
+        &self,
+        rows: &Rows<Renderer::Paragraph>,
+        index: usize,
+        renderer: &Renderer,
+    ) -> f32 {
+        let Some(option) = self.options.get(index) else {
+            return 0.0;
+        };
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let label_font = self.label_font(index, renderer);
+        let label_width = match &rows.rich_labels[index] {
+            Some(paragraph) => paragraph.min_width(),
+            None => self.text_width(&option.to_string(), text_size, label_font),
+        };
+
+        let shortcut_width = self
+            .shortcut
+            .and_then(|shortcut| shortcut(option))
+            .map_or(0.0, |shortcut| {
+                SHORTCUT_SPACING + self.text_width(&shortcut, text_size, font)
+            });
+
+        let description_width =
+            self.description_of(index).map_or(0.0, |description| {
+                self.text_width(
+                    &description,
+                    description_text_size(text_size),
+                    font,
+                )
+            });
+
+        (label_width + shortcut_width + self.indicator_width())
+            .max(description_width)
+            + self.padding.horizontal()
+    }
+
+    /// Calculate the width reserved at the right edge of every row for the
+    /// status indicator or the busy spinner of its option
+    fn indicator_width(&self) -> f32 {
+        if self.indicator.is_some() || self.row_busy.is_some() {
+            INDICATOR_WIDTH
+        } else {
+            0.0
+        }
+    }
+
+    /// Get the font of the label of the option at the given index
+    fn label_font(&self, index: usize, renderer: &Renderer) -> Renderer::Font {
+        self.option_font
+            .and_then(|option_font| option_font(self.options.get(index)?))
+            .or(self.font)
+            .unwrap_or_else(|| renderer.default_font())
+    }
+
+    /// Shape the styled segments of the label of the option at the given
+    /// index as a single paragraph, if it has any, wrapping it within the
+    /// given width if wrapping is enabled
+    ///
+    /// The segments are left without their colors unless `is_colored` is set.
+    fn rich_paragraph(
+        &self,
+        index: usize,
+        width: f32,
+        is_colored: bool,
+        renderer: &Renderer,
+    ) -> Option<Renderer::Paragraph> {
+        let segments = self
+            .rich_label
+            .zip(self.options.get(index))
+            .map(|(rich_label, option)| rich_label(option))
+            .filter(|segments| !segments.is_empty())?;
+
+        let spans: Vec<text::Span<'_, (), Renderer::Font>> = segments
+            .iter()
+            .map(|(content, color)| {
+                text::Span::new(content.as_str())
+                    .color_maybe(is_colored.then_some(*color))
+            })
+            .collect();
+
+        Some(Renderer::Paragraph::with_spans(Text {
+            content: &spans,
+            bounds: Size::new(
+                if self.text_wrapping.is_some() {
+                    width
+                } else {
+                    f32::INFINITY
+                },
+                f32::INFINITY,
+            ),
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.text_line_height,
+            font: self.label_font(index, renderer),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: self.text_shaping,
+            wrapping: self.text_wrapping.unwrap_or(text::Wrapping::None),
+        }))
+    }
+
+    /// Calculate the width available to the label of an option within a
+    /// list of the given width
+    fn label_width(&self, width: f32) -> f32 {
+        width / self.columns.max(1) as f32
+            - self.padding.horizontal()
+            - self.indicator_width()
+    }
+
+    /// Measure the width of some single-line text in the given font
+    fn text_width(
+        &self,
+        content: &str,
+        size: Pixels,
+        font: Renderer::Font,
+    ) -> f32 {
+        Renderer::Paragraph::with_text(Text {
+            content,
+            bounds: Size::INFINITY,
+            size,
+            line_height: self.text_line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::None,
+        })
+        .min_width()
+    }
+
+    /// Get the indices of the options, in display order
+    fn indices(&self) -> Box<dyn Iterator<Item = usize>> {
+        if self.reverse {
+            Box::new((0..self.options.len()).rev())
+        } else {
+            Box::new(0..self.options.len())
+        }
+    }
+
+    /// Get the rows of the list, in display order
+    fn rows(&self) -> Box<dyn Iterator<Item = Row> + '_> {
+        let rows = self
+            .header
+            .map(|_| Row::Header)
+            .into_iter()
+            .chain(
+                self.pinned_option
+                    .filter(|index| *index < self.options.len())
+                    .map(Row::Pinned),
+            )
+            .chain(self.indices().flat_map(|index| {
+                self.divider_of(index)
+                    .map(|_| Row::Divider(index))
+                    .into_iter()
+                    .chain(std::iter::once(Row::Option(index)))
+            }))
+            .chain(
+                self.no_results_message
+                    .filter(|_| self.options.is_empty())
+                    .map(|_| Row::Empty),
+            )
+            .chain(self.close_button.then_some(Row::Close));
+
+        Box::new(rows)
+    }
+
+    /// Calculate the height of a row, including the divider below a pinned
+    /// option
+    fn row_height(
+        &self,
+        rows: &Rows<Renderer::Paragraph>,
+        row: Row,
+        renderer: &Renderer,
+    ) -> f32 {
+        match row {
+            Row::Header | Row::Empty | Row::Close => {
+                let text_size =
+                    self.text_size.unwrap_or_else(|| renderer.default_size());
+
+                f32::from(self.text_line_height.to_absolute(text_size))
+                    + self.padding.vertical()
+            }
+            Row::Divider(index) => {
+                if self.divider_of(index).is_some_and(str::is_empty) {
+                    DIVIDER_HEIGHT
+                } else {
+                    let text_size = description_text_size(
+                        self.text_size
+                            .unwrap_or_else(|| renderer.default_size()),
+                    );
+
+                    f32::from(self.text_line_height.to_absolute(text_size))
+                        .max(DIVIDER_HEIGHT)
+                }
+            }
+            Row::Option(index) => self.option_height(rows, index, renderer),
+            Row::Pinned(index) => {
+                self.option_height(rows, index, renderer)
+                    + PINNED_DIVIDER_HEIGHT
+            }
+        }
+    }
+
+    /// Calculate the height of the option at the given index, including the
+    /// line of its description, if any
+    fn option_height(
+        &self,
+        rows: &Rows<Renderer::Paragraph>,
+        index: usize,
+        renderer: &Renderer,
+    ) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let label_height = rows.label_heights[index] + self.padding.vertical();
+
+        if self.description_of(index).is_some() {
+            label_height
+                + f32::from(
+                    self.text_line_height
+                        .to_absolute(description_text_size(text_size)),
+                )
+        } else {
+            label_height
+        }
+    }
+
+    /// Calculate the height of the label of the option at the given index,
+    /// measuring its wrapped lines within a list of the given width if
+    /// wrapping is enabled
+    fn label_height(
+        &self,
+        rows: &Rows<Renderer::Paragraph>,
+        index: usize,
+        width: f32,
+        renderer: &Renderer,
+    ) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height =
+            f32::from(self.text_line_height.to_absolute(text_size));
+
+        if let Some(paragraph) = &rows.rich_labels[index] {
+            return paragraph.min_bounds().height.max(line_height);
+        }
+
+        let (Some(wrapping), Some(option)) =
+            (self.text_wrapping, self.options.get(index))
+        else {
+            return line_height;
+        };
+
+        if wrapping == text::Wrapping::None {
+            return line_height;
+        }
+
+        let paragraph = Renderer::Paragraph::with_text(Text {
+            content: &option.to_string(),
+            bounds: Size::new(self.label_width(width), f32::INFINITY),
+            size: text_size,
+            line_height: self.text_line_height,
+            font: self.label_font(index, renderer),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: self.text_shaping,
+            wrapping,
+        });
+
+        paragraph.min_bounds().height.max(line_height)
+    }
+
+    /// Get the text of the divider above the option at the given index, if
+    /// there is a divider
+    ///
+    /// Options are laid out in a grid without dividers.
+    fn divider_of(&self, index: usize) -> Option<&str> {
+        if self.columns > 1 && self.orientation == Orientation::Vertical {
+            return None;
+        }
+
+        self.dividers.as_ref()?.get(index)?.as_deref()
+    }
+
+    /// Get the description of the option at the given index, if any
+    fn description_of(&self, index: usize) -> Option<String> {
+        self.description
+            .and_then(|description| description(self.options.get(index)?))
     }
 
     /// Check if an option at the given index is disabled
@@ -219,6 +1636,7 @@ where
     }
 }
 
+#[allow(clippy::type_complexity)]
 struct Overlay<'a, 'b, Message, Theme, Renderer>
 where
     Theme: Catalog,
@@ -226,9 +1644,27 @@ where
 {
     position: Point,
     state: &'a mut Tree,
-    list: Scrollable<'a, Message, Theme, Renderer>,
+    list: Element<'a, Message, Theme, Renderer>,
     width: f32,
+    trap_scroll: bool,
+    resizable: bool,
+    snap_rows: bool,
+    overscroll: bool,
     target_height: f32,
+    is_upward: bool,
+    opened_upward: &'a mut Option<bool>,
+    on_flip: Option<&'a dyn Fn(bool) -> Message>,
+    resize: &'a mut Resize,
+    scrolled_at: &'a mut Option<Instant>,
+    bounce: &'a mut Option<Bounce>,
+    content_height: &'a mut Option<f32>,
+    orientation: Orientation,
+    scrollable_id: Option<scrollable::Id>,
+    pending_scroll: &'a mut Option<Scroll>,
+    border_radius: Option<border::Radius>,
+    border: Option<Box<dyn Fn(&Theme) -> Border + 'a>>,
+    alignment: alignment::Horizontal,
+    target_width: f32,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -251,43 +1687,418 @@ where
             state,
             options,
             disabled,
+            dividers,
             hovered_option,
             on_selected,
             on_option_hovered,
+            on_disabled_hover,
+            on_option_double_click,
+            on_cursor,
+            description,
+            shortcut,
+            rich_label,
+            indicator,
+            tooltip,
+            option_font,
+            row_busy,
+            disabled_style,
+            on_scroll,
+            on_flip,
+            on_scroll_start,
+            pinned_option,
+            header,
+            sticky_header,
+            close_button,
+            close_button_label,
+            no_results_message,
             width,
+            trap_scroll,
+            resizable,
+            snap_rows,
+            overscroll,
             padding,
             font,
             text_size,
             text_line_height,
             text_shaping,
+            text_wrapping,
+            orientation,
+            columns,
+            reverse,
+            scroll,
+            scrollbar,
+            border_radius,
+            border,
+            alignment,
+            target_width,
             class,
         } = menu;
 
-        let list = Scrollable::new(List {
+        let State {
+            tree,
+            pressed_option,
+            hovered_disabled,
+            hover_locked_until,
+            pending_scroll,
+            opened_upward,
+            hover_tooltip,
+            spinner,
+            cursor_report,
+            is_at_scroll_start,
+            previous_click,
+            is_keyboard_navigated,
+            resize,
+            scrolled_at,
+            bounce,
+            content_height,
+        } = state;
+
+        let scrollbar = match scrollbar {
+            ScrollbarPolicy::Auto => scrollable::Scrollbar::default(),
+            ScrollbarPolicy::AlwaysOn => {
+                scrollable::Scrollbar::default().spacing(0)
+            }
+            ScrollbarPolicy::AlwaysOff => {
+                scrollable::Scrollbar::default().width(0).scroller_width(0)
+            }
+        };
+
+        let direction = match orientation {
+            Orientation::Vertical => scrollable::Direction::Vertical(scrollbar),
+            Orientation::Horizontal => {
+                scrollable::Direction::Horizontal(scrollbar)
+            }
+        };
+
+        let list = List {
             options,
             disabled,
+            dividers,
             hovered_option,
+            pressed_option,
+            hovered_disabled,
+            hover_tooltip,
+            spinner,
+            cursor_report,
+            is_at_scroll_start,
+            previous_click,
+            is_keyboard_navigated,
+            on_scroll_start,
+            hover_locked_until: *hover_locked_until,
             on_selected,
             on_option_hovered,
+            on_disabled_hover,
+            on_option_double_click,
+            on_cursor,
+            description,
+            shortcut,
+            rich_label,
+            indicator,
+            tooltip,
+            option_font,
+            row_busy,
+            disabled_style,
+            pinned_option,
+            header,
+            sticky_header: sticky_header
+                && orientation == Orientation::Vertical,
+            close_button,
+            close_button_label,
+            no_results_message,
             font,
             text_size,
             text_line_height,
             text_shaping,
+            text_wrapping,
+            orientation,
+            columns,
+            reverse,
             padding,
+            border_radius,
             class,
-        });
+        };
 
-        state.tree.diff(&list as &dyn Widget<_, _, _>);
+        let (list, scrollable_id) = if scroll {
+            let scrollable_id = scrollable::Id::unique();
+
+            let mut list = Scrollable::with_direction(list, direction)
+                .id(scrollable_id.clone());
+
+            if let Some(on_scroll) = on_scroll {
+                list = list.on_scroll(move |viewport| {
+                    on_scroll(viewport.absolute_offset().y)
+                });
+            }
+
+            (Element::from(list), Some(scrollable_id))
+        } else {
+            (Element::from(list), None)
+        };
+
+        tree.diff(&list);
 
         Self {
             position,
-            state: &mut state.tree,
+            state: tree,
             list,
             width,
+            trap_scroll,
+            resizable,
+            snap_rows,
+            overscroll,
             target_height,
+            is_upward: false,
+            opened_upward,
+            on_flip,
+            resize,
+            scrolled_at,
+            bounce,
+            content_height,
+            orientation,
+            scrollable_id,
+            pending_scroll,
+            border_radius,
+            border,
+            alignment,
+            target_width,
             class,
         }
     }
+
+    /// Check if the cursor is over the zone at the right edge of the menu
+    /// that can be dragged to resize it
+    fn is_over_resize_zone(
+        &self,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> bool {
+        self.resizable
+            && cursor.is_over(Rectangle {
+                x: bounds.x + bounds.width - RESIZE_ZONE_WIDTH,
+                width: RESIZE_ZONE_WIDTH,
+                ..bounds
+            })
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Get the cursor over the list, moved along with its options while they
+    /// bounce
+    fn list_cursor(&self, cursor: mouse::Cursor) -> mouse::Cursor {
+        match (*self.bounce, cursor) {
+            (Some(bounce), mouse::Cursor::Available(position)) => {
+                mouse::Cursor::Available(
+                    position - Vector::new(0.0, bounce.offset),
+                )
+            }
+            _ => cursor,
+        }
+    }
+
+    /// Get the rows of the list, as placed by its last layout
+    fn rows(&self) -> &Rows<Renderer::Paragraph> {
+        // A scrollable list is the only child of its scrollable
+        let list = if self.scrollable_id.is_some() {
+            &self.state.children[0]
+        } else {
+            &*self.state
+        };
+
+        list.state.downcast_ref::<Rows<Renderer::Paragraph>>()
+    }
+
+    /// Get the scroll offset of the list and the largest offset it can be
+    /// scrolled to
+    fn scroll_position(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<(f32, f32)> {
+        struct Position {
+            position: Option<(f32, f32)>,
+        }
+
+        impl Operation for Position {
+            fn container(
+                &mut self,
+                _id: Option<&widget::Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+            ) {
+                operate_on_children(self);
+            }
+
+            fn scrollable(
+                &mut self,
+                _state: &mut dyn operation::Scrollable,
+                _id: Option<&widget::Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
+                translation: Vector,
+            ) {
+                self.position = Some((
+                    translation.y,
+                    (content_bounds.height - bounds.height).max(0.0),
+                ));
+            }
+        }
+
+        let mut position = Position { position: None };
+
+        self.list.as_widget().operate(
+            self.state,
+            layout,
+            renderer,
+            &mut position,
+        );
+
+        position.position
+    }
+
+    /// Scroll the list to the start of the option nearest to its current
+    /// scroll offset, or to its end if that is nearer
+    fn snap_to_nearest_row(&mut self, layout: Layout<'_>, renderer: &Renderer) {
+        struct Snap {
+            targets: Vec<f32>,
+        }
+
+        impl Operation for Snap {
+            fn container(
+                &mut self,
+                _id: Option<&widget::Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+            ) {
+                operate_on_children(self);
+            }
+
+            fn scrollable(
+                &mut self,
+                state: &mut dyn operation::Scrollable,
+                _id: Option<&widget::Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
+                translation: Vector,
+            ) {
+                let end = (content_bounds.height - bounds.height).max(0.0);
+                let distance = |target: &f32| (target - translation.y).abs();
+
+                if let Some(target) = self
+                    .targets
+                    .iter()
+                    .copied()
+                    .chain(std::iter::once(end))
+                    .filter(|target| *target <= end)
+                    .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+                    .filter(|target| distance(target) >= 0.5)
+                {
+                    state.scroll_to(AbsoluteOffset {
+                        x: translation.x,
+                        y: target,
+                    });
+                }
+            }
+        }
+
+        let targets = self
+            .rows()
+            .placements
+            .iter()
+            .filter(|(row, _)| matches!(row, Row::Option(_)))
+            .map(|(_, bounds)| bounds.y)
+            .collect();
+
+        self.list.as_widget().operate(
+            self.state,
+            layout,
+            renderer,
+            &mut Snap { targets },
+        );
+    }
+
+    /// Scroll the list as little as needed for the given bounds of an option
+    /// to be fully visible, keeping its offset if they already are
+    fn scroll_into_view(
+        &mut self,
+        option: Rectangle,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) {
+        struct IntoView {
+            option: Rectangle,
+            orientation: Orientation,
+        }
+
+        impl Operation for IntoView {
+            fn container(
+                &mut self,
+                _id: Option<&widget::Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+            ) {
+                operate_on_children(self);
+            }
+
+            fn scrollable(
+                &mut self,
+                state: &mut dyn operation::Scrollable,
+                _id: Option<&widget::Id>,
+                bounds: Rectangle,
+                _content_bounds: Rectangle,
+                translation: Vector,
+            ) {
+                let (start, length, offset, viewport) = match self.orientation {
+                    Orientation::Vertical => (
+                        self.option.y,
+                        self.option.height,
+                        translation.y,
+                        bounds.height,
+                    ),
+                    Orientation::Horizontal => (
+                        self.option.x,
+                        self.option.width,
+                        translation.x,
+                        bounds.width,
+                    ),
+                };
+
+                // The leading edge wins over the trailing one when the
+                // option is longer than the list
+                let target = if start < offset {
+                    start
+                } else if start + length > offset + viewport {
+                    (start + length - viewport).min(start)
+                } else {
+                    return;
+                };
+
+                state.scroll_to(match self.orientation {
+                    Orientation::Vertical => AbsoluteOffset {
+                        x: translation.x,
+                        y: target,
+                    },
+                    Orientation::Horizontal => AbsoluteOffset {
+                        x: target,
+                        y: translation.y,
+                    },
+                });
+            }
+        }
+
+        self.list.as_widget().operate(
+            self.state,
+            layout,
+            renderer,
+            &mut IntoView {
+                option,
+                orientation: self.orientation,
+            },
+        );
+    }
 }
 
 impl<'a, 'b, Message, Theme, Renderer>
@@ -298,14 +2109,42 @@ where
     Renderer: text::Renderer,
 {
     fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
-        let space_below =
-            bounds.height - (self.position.y + self.target_height);
-        let space_above = self.position.y;
+        // The menu is positioned against the whole window, so it is never
+        // clipped by a scrolled ancestor of the target. The target itself
+        // may be partially scrolled out of view, so its edges are clamped
+        // to the window to keep the menu fully visible.
+        let top = self.position.y.clamp(0.0, bounds.height);
+        let bottom =
+            (self.position.y + self.target_height).clamp(0.0, bounds.height);
+
+        let space_below = bounds.height - bottom;
+        let space_above = top;
+
+        // A menu widened by the user never becomes narrower than its
+        // natural width
+        let menu_width = self
+            .resize
+            .width
+            .filter(|_| self.resizable)
+            .map_or(self.width, |width| width.max(self.width));
+
+        // Keep the menu within the window, moving it to the left if needed
+        let width = menu_width.min(bounds.width);
+        let x = match self.alignment {
+            alignment::Horizontal::Left => self.position.x,
+            alignment::Horizontal::Center => {
+                self.position.x + (self.target_width - width) / 2.0
+            }
+            alignment::Horizontal::Right => {
+                self.position.x + self.target_width - width
+            }
+        };
+        let x = x.min(bounds.width - width).max(0.0);
 
         let limits = layout::Limits::new(
             Size::ZERO,
             Size::new(
-                bounds.width - self.position.x,
+                bounds.width - x,
                 if space_below > space_above {
                     space_below
                 } else {
@@ -313,18 +2152,88 @@ where
                 },
             ),
         )
-        .width(self.width);
+        .width(menu_width);
+
+        // Without scrolling, the menu is as tall as its content
+        let limits = if self.scrollable_id.is_some() {
+            limits
+        } else {
+            limits.max_height(f32::INFINITY)
+        };
 
-        let node = self.list.layout(self.state, renderer, &limits);
+        let node = self.list.as_widget().layout(self.state, renderer, &limits);
         let size = node.size();
 
+        *self.content_height = Some(self.rows().height());
+
+        if let (Some(scroll), Some(scrollable_id)) =
+            (self.pending_scroll.take(), self.scrollable_id.clone())
+        {
+            let index = match scroll {
+                Scroll::To(index) | Scroll::IntoView(index) => index,
+            };
+
+            if let Some(option) = self
+                .rows()
+                .placements
+                .iter()
+                .find(|(row, _)| *row == Row::Option(index))
+                .map(|(_, bounds)| *bounds)
+            {
+                match scroll {
+                    Scroll::To(_) => {
+                        let offset = match self.orientation {
+                            Orientation::Vertical => AbsoluteOffset {
+                                x: 0.0,
+                                y: option.y,
+                            },
+                            Orientation::Horizontal => AbsoluteOffset {
+                                x: option.x,
+                                y: 0.0,
+                            },
+                        };
+
+                        self.list.as_widget().operate(
+                            self.state,
+                            Layout::new(&node),
+                            renderer,
+                            &mut operation::scrollable::scroll_to(
+                                scrollable_id.into(),
+                                offset,
+                            ),
+                        );
+                    }
+                    Scroll::IntoView(_) => {
+                        self.scroll_into_view(
+                            option,
+                            Layout::new(&node),
+                            renderer,
+                        );
+                    }
+                }
+            }
+        }
+
+        self.is_upward = space_below <= space_above;
+
         node.move_to(if space_below > space_above {
-            self.position + Vector::new(0.0, self.target_height)
+            Point::new(x, bottom)
         } else {
-            self.position - Vector::new(0.0, size.height)
+            Point::new(x, top - size.height)
         })
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.list
+            .as_widget()
+            .operate(self.state, layout, renderer, operation);
+    }
+
     fn on_event(
         &mut self,
         event: Event,
@@ -334,12 +2243,163 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if let Some(on_flip) = self.on_flip {
+            if *self.opened_upward != Some(self.is_upward) {
+                *self.opened_upward = Some(self.is_upward);
+
+                shell.publish(on_flip(self.is_upward));
+            }
+        }
+
         let bounds = layout.bounds();
 
-        self.list.on_event(
-            self.state, event, layout, cursor, renderer, clipboard, shell,
+        if self.resizable {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                )) if self.is_over_resize_zone(bounds, cursor) => {
+                    if let Some(position) = cursor.position() {
+                        self.resize.drag = Some(Drag {
+                            origin: position.x,
+                            width: bounds.width,
+                        });
+                    }
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some(drag) = self.resize.drag {
+                        self.resize.width =
+                            Some(drag.width + position.x - drag.origin);
+
+                        shell.invalidate_layout();
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) if self.resize.drag.is_some() => {
+                    self.resize.drag = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let is_wheel_scroll =
+            matches!(event, Event::Mouse(mouse::Event::WheelScrolled { .. }));
+        let is_snapping = self.snap_rows
+            && self.scrollable_id.is_some()
+            && self.orientation == Orientation::Vertical;
+
+        if is_snapping {
+            if let Event::Window(window::Event::RedrawRequested(now)) = event {
+                if self
+                    .scrolled_at
+                    .is_some_and(|scrolled_at| now >= scrolled_at + SNAP_DELAY)
+                {
+                    *self.scrolled_at = None;
+
+                    self.snap_to_nearest_row(layout, renderer);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
+        let is_bouncing = self.overscroll
+            && self.scrollable_id.is_some()
+            && self.orientation == Orientation::Vertical;
+
+        if is_bouncing {
+            match event {
+                Event::Mouse(mouse::Event::WheelScrolled { delta })
+                    if cursor.is_over(bounds) =>
+                {
+                    let delta = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => {
+                            y * PIXELS_PER_LINE
+                        }
+                        mouse::ScrollDelta::Pixels { y, .. } => y,
+                    };
+
+                    if let Some((offset, end)) =
+                        self.scroll_position(layout, renderer)
+                    {
+                        // Scrolling up moves the options down
+                        let is_past_start = delta > 0.0 && offset <= 0.5;
+                        let is_past_end = delta < 0.0 && offset >= end - 0.5;
+
+                        if is_past_start || is_past_end {
+                            let offset = self.bounce.map_or(0.0, |b| b.offset)
+                                + delta * OVERSCROLL_RESISTANCE;
+
+                            *self.bounce = Some(Bounce {
+                                offset: offset
+                                    .clamp(-OVERSCROLL_MAX, OVERSCROLL_MAX),
+                                ticked_at: Instant::now(),
+                            });
+
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        }
+                    }
+                }
+                Event::Window(window::Event::RedrawRequested(now)) => {
+                    if let Some(bounce) = self.bounce.as_mut() {
+                        let elapsed = now
+                            .saturating_duration_since(bounce.ticked_at)
+                            .as_secs_f32();
+
+                        bounce.offset *= (-OVERSCROLL_DECAY * elapsed).exp();
+                        bounce.ticked_at = now;
+
+                        if bounce.offset.abs() < 0.5 {
+                            *self.bounce = None;
+                        } else {
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let is_scroll_end = (is_wheel_scroll && cursor.is_over(bounds))
+            || matches!(event, Event::Touch(touch::Event::FingerLifted { .. }));
+
+        // The options are hit at the position they are drawn at
+        let list_cursor = self.list_cursor(cursor);
+
+        let status = self.list.as_widget_mut().on_event(
+            self.state,
+            event,
+            layout,
+            list_cursor,
+            renderer,
+            clipboard,
+            shell,
             &bounds,
-        )
+        );
+
+        if is_snapping && is_scroll_end {
+            let now = Instant::now();
+
+            *self.scrolled_at = Some(now);
+
+            shell.request_redraw(window::RedrawRequest::At(now + SNAP_DELAY));
+        }
+
+        if self.trap_scroll && is_wheel_scroll && cursor.is_over(bounds) {
+            event::Status::Captured
+        } else {
+            status
+        }
     }
 
     fn mouse_interaction(
@@ -349,8 +2409,19 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.list
-            .mouse_interaction(self.state, layout, cursor, viewport, renderer)
+        if self.resize.drag.is_some()
+            || self.is_over_resize_zone(layout.bounds(), cursor)
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        self.list.as_widget().mouse_interaction(
+            self.state,
+            layout,
+            self.list_cursor(cursor),
+            viewport,
+            renderer,
+        )
     }
 
     fn draw(
@@ -363,23 +2434,57 @@ where
     ) {
         let bounds = layout.bounds();
 
-        let style = Catalog::style(theme, self.class);
+        let mut style = Catalog::style(theme, self.class);
+
+        if let Some(border_radius) = self.border_radius {
+            style.border.radius = border_radius;
+        }
+
+        if let Some(border) = &self.border {
+            let border = border(theme);
+
+            style.border.width = border.width;
+            style.border.color = border.color;
+        }
 
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border: style.border,
-                ..renderer::Quad::default()
+                shadow: style.shadow.unwrap_or_default(),
             },
             style.background,
         );
 
-        self.list.draw(
-            self.state, renderer, theme, defaults, layout, cursor, &bounds,
-        );
+        if let Some(bounce) = *self.bounce {
+            // The options are clipped to the menu while they bounce
+            renderer.with_layer(bounds, |renderer| {
+                renderer.with_translation(
+                    Vector::new(0.0, bounce.offset),
+                    |renderer| {
+                        // The viewport moves the other way, so the options
+                        // entering it are drawn too
+                        self.list.as_widget().draw(
+                            self.state,
+                            renderer,
+                            theme,
+                            defaults,
+                            layout,
+                            self.list_cursor(cursor),
+                            &(bounds - Vector::new(0.0, bounce.offset)),
+                        );
+                    },
+                );
+            });
+        } else {
+            self.list.as_widget().draw(
+                self.state, renderer, theme, defaults, layout, cursor, &bounds,
+            );
+        }
     }
 }
 
+#[allow(clippy::type_complexity)]
 struct List<'a, 'b, T, Message, Theme, Renderer>
 where
     Theme: Catalog,
@@ -387,14 +2492,47 @@ where
 {
     options: &'a [T],
     disabled: Option<Vec<bool>>,
+    dividers: Option<Vec<Option<String>>>,
     hovered_option: &'a mut Option<usize>,
-    on_selected: Box<dyn FnMut(T) -> Message + 'a>,
+    pressed_option: &'a mut Option<Press>,
+    hovered_disabled: &'a mut Option<usize>,
+    hover_tooltip: &'a mut Option<Tooltip>,
+    spinner: &'a mut Option<Spinner>,
+    cursor_report: &'a mut CursorReport,
+    is_at_scroll_start: &'a mut Option<bool>,
+    previous_click: &'a mut Option<(usize, mouse::Click)>,
+    is_keyboard_navigated: &'a mut bool,
+    on_scroll_start: Option<Message>,
+    hover_locked_until: Option<Instant>,
+    on_selected: Box<dyn FnMut(T, SelectSource) -> Option<Message> + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    on_disabled_hover: Option<&'a dyn Fn(T) -> Message>,
+    on_option_double_click: Option<&'a dyn Fn(T) -> Message>,
+    on_cursor: Option<&'a dyn Fn(Point) -> Message>,
+    description: Option<&'a dyn Fn(&T) -> Option<String>>,
+    shortcut: Option<&'a dyn Fn(&T) -> Option<String>>,
+    rich_label: Option<&'a dyn Fn(&T) -> Vec<(String, Color)>>,
+    indicator: Option<&'a dyn Fn(&T) -> Option<Color>>,
+    tooltip: Option<&'a dyn Fn(&T) -> Option<String>>,
+    option_font: Option<&'a dyn Fn(&T) -> Option<Renderer::Font>>,
+    row_busy: Option<&'a dyn Fn(&T) -> bool>,
+    disabled_style: Option<&'a dyn Fn(&T) -> Option<DisabledStyle>>,
+    pinned_option: Option<usize>,
+    header: Option<&'a str>,
+    sticky_header: bool,
+    close_button: bool,
+    close_button_label: &'a str,
+    no_results_message: Option<&'a str>,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
+    text_wrapping: Option<text::Wrapping>,
+    orientation: Orientation,
+    columns: usize,
+    reverse: bool,
     font: Option<Renderer::Font>,
+    border_radius: Option<border::Radius>,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -407,116 +2545,433 @@ where
 {
     fn size(&self) -> Size<Length> {
         Size {
-            width: Length::Fill,
+            width: match self.orientation {
+                Orientation::Vertical => Length::Fill,
+                Orientation::Horizontal => Length::Shrink,
+            },
             height: Length::Shrink,
         }
     }
 
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<Rows<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(Rows::<Renderer::Paragraph>::default())
+    }
+
     fn layout(
         &self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        use std::f32;
+        let rows = tree.state.downcast_mut::<Rows<Renderer::Paragraph>>();
+
+        // A filling list is as wide as its limits, which already account for
+        // any resizing, so its rows can be placed before its height is known
+        let width = limits.max().width;
+
+        // Rich and wrapped labels are shaped here only, since both the
+        // placements and the drawing need them
+        rows.rich_labels = (0..self.options.len())
+            .map(|index| {
+                self.rich_paragraph(
+                    index,
+                    self.label_width(width),
+                    !self.is_disabled(index),
+                    renderer,
+                )
+            })
+            .collect();
+
+        rows.label_heights = (0..self.options.len())
+            .map(|index| self.label_height(rows, index, width, renderer))
+            .collect();
+
+        let (size, placements) = match self.orientation {
+            Orientation::Vertical => {
+                let placements =
+                    self.placements(rows, Size::new(width, 0.0), renderer);
+                let intrinsic = Size::new(
+                    0.0,
+                    placements
+                        .last()
+                        .map_or(0.0, |(_, bounds)| bounds.y + bounds.height),
+                );
 
-        let text_size =
-            self.text_size.unwrap_or_else(|| renderer.default_size());
+                (
+                    limits.resolve(Length::Fill, Length::Shrink, intrinsic),
+                    placements,
+                )
+            }
+            Orientation::Horizontal => {
+                // Rows are measured once and stretched to the height of the
+                // list afterwards
+                let mut placements =
+                    self.placements(rows, Size::ZERO, renderer);
+                let intrinsic = Size::new(
+                    placements
+                        .last()
+                        .map_or(0.0, |(_, bounds)| bounds.x + bounds.width),
+                    self.rows().fold(0.0, |height: f32, row| {
+                        height.max(match row {
+                            Row::Pinned(index) | Row::Option(index) => {
+                                self.option_height(rows, index, renderer)
+                            }
+                            Row::Header | Row::Empty | Row::Close => {
+                                self.row_height(rows, row, renderer)
+                            }
+                            Row::Divider(_) => 0.0,
+                        })
+                    }),
+                );
 
-        let text_line_height = self.text_line_height.to_absolute(text_size);
+                let size =
+                    limits.resolve(Length::Shrink, Length::Shrink, intrinsic);
 
-        let size = {
-            let intrinsic = Size::new(
-                0.0,
-                (f32::from(text_line_height) + self.padding.vertical())
-                    * self.options.len() as f32,
-            );
+                for (_, bounds) in &mut placements {
+                    bounds.height = size.height;
+                }
 
-            limits.resolve(Length::Fill, Length::Shrink, intrinsic)
+                (size, placements)
+            }
         };
 
+        rows.placements = placements;
+
         layout::Node::new(size)
     }
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        renderer: &Renderer,
+        _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
+        let rows = tree.state.downcast_ref::<Rows<Renderer::Paragraph>>();
+
+        // The options scrolled under a sticky header cannot be interacted with
+        let cursor = if self
+            .sticky_header_bounds(rows, layout.bounds(), viewport)
+            .is_some_and(|header_bounds| cursor.is_over(header_bounds))
+        {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+            if let Some((on_cursor, position)) =
+                self.on_cursor.zip(cursor.position_in(layout.bounds()))
+            {
+                let now = Instant::now();
+
+                match self.cursor_report.reported_at {
+                    // Positions are throttled, keeping only the last one
+                    Some(at) if now < at + CURSOR_REPORT_INTERVAL => {
+                        if self.cursor_report.pending.is_none() {
+                            shell.request_redraw(window::RedrawRequest::At(
+                                at + CURSOR_REPORT_INTERVAL,
+                            ));
+                        }
+
+                        self.cursor_report.pending = Some(position);
+                    }
+                    _ => {
+                        shell.publish(on_cursor(position));
+
+                        *self.cursor_report = CursorReport {
+                            reported_at: Some(now),
+                            pending: None,
+                        };
+                    }
+                }
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if cursor
+                .position_in(layout.bounds())
+                .is_some_and(|position| self.is_close_button_at(rows, position))
+            {
+                // The press falls through to the owner of the menu, which
+                // closes it without selecting anything
+                *self.hovered_option = None;
+
+                return event::Status::Ignored;
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if let Some(cursor_position) =
                     cursor.position_in(layout.bounds())
                 {
                     if let Some(clicked_index) =
-                        self.option_index_at(cursor_position, renderer)
+                        self.option_index_at(rows, cursor_position)
                     {
-                        if !self.is_disabled(clicked_index) {
-                            if let Some(option) =
-                                self.options.get(clicked_index)
+                        if let Some(on_option_double_click) = self
+                            .on_option_double_click
+                            .filter(|_| !self.is_disabled(clicked_index))
+                        {
+                            // Clicks only count as a double click on the
+                            // same option
+                            let click = mouse::Click::new(
+                                cursor_position,
+                                mouse::Button::Left,
+                                self.previous_click
+                                    .filter(|(index, _)| {
+                                        *index == clicked_index
+                                    })
+                                    .map(|(_, click)| click),
+                            );
+
+                            *self.previous_click = Some((clicked_index, click));
+
+                            if let (mouse::click::Kind::Double, Some(option)) =
+                                (click.kind(), self.options.get(clicked_index))
                             {
-                                shell.publish((self.on_selected)(
+                                if let Some(message) = (self.on_selected)(
+                                    option.clone(),
+                                    SelectSource::Mouse,
+                                ) {
+                                    shell.publish(message);
+                                }
+
+                                shell.publish(on_option_double_click(
                                     option.clone(),
                                 ));
                             }
+                        } else if !self.is_disabled(clicked_index) {
+                            if let Some(message) = self
+                                .options
+                                .get(clicked_index)
+                                .and_then(|option| {
+                                    (self.on_selected)(
+                                        option.clone(),
+                                        SelectSource::Mouse,
+                                    )
+                                })
+                            {
+                                shell.publish(message);
+                            }
                         }
                         return event::Status::Captured;
                     }
                 }
             }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if self
+                    .hover_locked_until
+                    .is_some_and(|until| Instant::now() < until) => {}
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 if let Some(cursor_position) =
                     cursor.position_in(layout.bounds())
                 {
+                    *self.is_keyboard_navigated = false;
+
                     if let Some(new_hovered_option) =
-                        self.option_index_at(cursor_position, renderer)
+                        self.option_index_at(rows, cursor_position)
                     {
+                        // The hovered option follows the cursor, even over
+                        // disabled options, which still cannot be selected
+                        let is_new_hover =
+                            *self.hovered_option != Some(new_hovered_option);
+
+                        *self.hovered_option = Some(new_hovered_option);
+
+                        if self.tooltip.is_some()
+                            && self.hover_tooltip.map(|tooltip| tooltip.option)
+                                != Some(new_hovered_option)
+                        {
+                            let at = Instant::now() + TOOLTIP_DELAY;
+
+                            *self.hover_tooltip = Some(Tooltip {
+                                option: new_hovered_option,
+                                at,
+                                is_shown: false,
+                            });
+
+                            shell.request_redraw(window::RedrawRequest::At(at));
+                        }
+
                         if !self.is_disabled(new_hovered_option) {
-                            if let Some(on_option_hovered) =
-                                self.on_option_hovered
+                            if let Some((on_option_hovered, option)) = self
+                                .on_option_hovered
+                                .zip(self.options.get(new_hovered_option))
+                                .filter(|_| is_new_hover)
                             {
-                                if *self.hovered_option
-                                    != Some(new_hovered_option)
-                                {
-                                    if let Some(option) =
-                                        self.options.get(new_hovered_option)
-                                    {
-                                        shell.publish(on_option_hovered(
-                                            option.clone(),
-                                        ));
-                                    }
-                                }
+                                shell
+                                    .publish(on_option_hovered(option.clone()));
+                            }
+
+                            *self.hovered_disabled = None;
+                        } else if *self.hovered_disabled
+                            != Some(new_hovered_option)
+                        {
+                            *self.hovered_disabled = Some(new_hovered_option);
+
+                            if let Some((on_disabled_hover, option)) = self
+                                .on_disabled_hover
+                                .zip(self.options.get(new_hovered_option))
+                            {
+                                shell
+                                    .publish(on_disabled_hover(option.clone()));
                             }
-                            *self.hovered_option = Some(new_hovered_option);
                         }
                         return event::Status::Captured;
                     }
                 }
+
+                *self.hovered_disabled = None;
+                *self.hover_tooltip = None;
             }
-            Event::Touch(touch::Event::FingerPressed { .. }) => {
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => {
                 if let Some(cursor_position) =
                     cursor.position_in(layout.bounds())
                 {
                     if let Some(new_hovered_option) =
-                        self.option_index_at(cursor_position, renderer)
+                        self.option_index_at(rows, cursor_position)
                     {
+                        *self.hovered_option = Some(new_hovered_option);
+
                         if !self.is_disabled(new_hovered_option) {
-                            *self.hovered_option = Some(new_hovered_option);
-                            if let Some(option) =
-                                self.options.get(new_hovered_option)
-                            {
-                                shell.publish((self.on_selected)(
+                            *self.pressed_option = Some(Press {
+                                option: new_hovered_option,
+                                at: Instant::now(),
+                                origin: position,
+                                is_released: false,
+                            });
+                        }
+
+                        // Let the scrollable track the finger, in case this
+                        // turns out to be a scroll
+                        return event::Status::Ignored;
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.pressed_option.is_some_and(|press| {
+                    !press.is_released
+                        && press.origin.distance(position) > TAP_THRESHOLD
+                }) =>
+            {
+                *self.pressed_option = None;
+            }
+            Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(press) = self.pressed_option.as_mut() {
+                    // Show the pressed feedback for a moment before the
+                    // selection is committed
+                    press.is_released = true;
+
+                    shell.request_redraw(window::RedrawRequest::At(
+                        press.at + PRESSED_FEEDBACK,
+                    ));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                *self.pressed_option = None;
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                // The visible options are scrolled by the distance between
+                // the start of the list and the start of the viewport
+                let offset = match self.orientation {
+                    Orientation::Vertical => viewport.y - layout.bounds().y,
+                    Orientation::Horizontal => viewport.x - layout.bounds().x,
+                };
+                let is_at_scroll_start = offset <= SCROLL_START_THRESHOLD;
+
+                if self.is_at_scroll_start.replace(is_at_scroll_start)
+                    == Some(false)
+                    && is_at_scroll_start
+                {
+                    // The list is rebuilt with its message on every frame
+                    if let Some(message) = self.on_scroll_start.take() {
+                        shell.publish(message);
+                    }
+                }
+
+                if let (Some(on_cursor), Some(position), Some(at)) = (
+                    self.on_cursor,
+                    self.cursor_report.pending,
+                    self.cursor_report.reported_at,
+                ) {
+                    if now < at + CURSOR_REPORT_INTERVAL {
+                        shell.request_redraw(window::RedrawRequest::At(
+                            at + CURSOR_REPORT_INTERVAL,
+                        ));
+                    } else {
+                        shell.publish(on_cursor(position));
+
+                        *self.cursor_report = CursorReport {
+                            reported_at: Some(now),
+                            pending: None,
+                        };
+                    }
+                }
+
+                let is_busy = self
+                    .row_busy
+                    .is_some_and(|row_busy| self.options.iter().any(row_busy));
+
+                if is_busy {
+                    let spinner = self.spinner.get_or_insert(Spinner {
+                        started_at: now,
+                        elapsed: Duration::ZERO,
+                    });
+
+                    spinner.elapsed = now - spinner.started_at;
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                } else {
+                    *self.spinner = None;
+                }
+
+                if let Some(tooltip) = self
+                    .hover_tooltip
+                    .as_mut()
+                    .filter(|tooltip| !tooltip.is_shown)
+                {
+                    if now < tooltip.at {
+                        shell.request_redraw(window::RedrawRequest::At(
+                            tooltip.at,
+                        ));
+                    } else {
+                        tooltip.is_shown = true;
+                    }
+                }
+
+                if let Some(press) =
+                    self.pressed_option.filter(|press| press.is_released)
+                {
+                    if now < press.at + PRESSED_FEEDBACK {
+                        shell.request_redraw(window::RedrawRequest::At(
+                            press.at + PRESSED_FEEDBACK,
+                        ));
+                    } else {
+                        *self.pressed_option = None;
+
+                        if let Some(message) =
+                            self.options.get(press.option).and_then(|option| {
+                                (self.on_selected)(
                                     option.clone(),
-                                ));
-                            }
+                                    SelectSource::Touch,
+                                )
+                            })
+                        {
+                            shell.publish(message);
                         }
-                        return event::Status::Captured;
                     }
                 }
             }
@@ -528,15 +2983,24 @@ where
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _viewport: &Rectangle,
-        renderer: &Renderer,
+        viewport: &Rectangle,
+        _renderer: &Renderer,
     ) -> mouse::Interaction {
+        let rows = tree.state.downcast_ref::<Rows<Renderer::Paragraph>>();
+
+        if self
+            .sticky_header_bounds(rows, layout.bounds(), viewport)
+            .is_some_and(|header_bounds| cursor.is_over(header_bounds))
+        {
+            return mouse::Interaction::default();
+        }
+
         if let Some(cursor_position) = cursor.position_in(layout.bounds()) {
             if let Some(hovered_index) =
-                self.option_index_at(cursor_position, renderer)
+                self.option_index_at(rows, cursor_position)
             {
                 if !self.is_disabled(hovered_index) {
                     return mouse::Interaction::Pointer;
@@ -549,52 +3013,220 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let style = Catalog::style(theme, self.class);
+        let rows = tree.state.downcast_ref::<Rows<Renderer::Paragraph>>();
+        let mut style = Catalog::style(theme, self.class);
         let bounds = layout.bounds();
 
+        if let Some(border_radius) = self.border_radius {
+            style.border.radius = border_radius;
+        }
+
         let text_size =
             self.text_size.unwrap_or_else(|| renderer.default_size());
-        let option_height =
-            f32::from(self.text_line_height.to_absolute(text_size))
-                + self.padding.vertical();
-
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height) as usize;
-        let end = ((offset + viewport.height) / option_height).ceil() as usize;
-
-        let visible_options = &self.options[start..end.min(self.options.len())];
-
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
-            let is_selected = *self.hovered_option == Some(i);
-            let is_disabled = self
-                .disabled
-                .as_ref()
-                .and_then(|d| d.get(i))
-                .copied()
-                .unwrap_or(false);
-
-            let bounds = Rectangle {
-                x: bounds.x,
-                y: bounds.y + (option_height * i as f32),
-                width: bounds.width,
-                height: option_height,
+        let label_height =
+            f32::from(self.text_line_height.to_absolute(text_size));
+
+        let is_horizontal = self.orientation == Orientation::Horizontal;
+
+        // A pinned option is also drawn in its place among the others, but
+        // only one of its rows is highlighted: the pinned one while the
+        // cursor is over it, and the other one otherwise
+        let hovered_row = self.hovered_option.map(|index| {
+            let is_pinned_hovered = !*self.is_keyboard_navigated
+                && cursor.position_in(bounds).is_some_and(|position| {
+                    rows.at(self.orientation, position)
+                        .any(|(row, _)| *row == Row::Pinned(index))
+                });
+
+            if is_pinned_hovered {
+                Row::Pinned(index)
+            } else {
+                Row::Option(index)
+            }
+        });
+
+        let (start, end) = if is_horizontal {
+            (
+                viewport.x - bounds.x,
+                viewport.x + viewport.width - bounds.x,
+            )
+        } else {
+            (
+                viewport.y - bounds.y,
+                viewport.y + viewport.height - bounds.y,
+            )
+        };
+
+        for &(row, row_bounds) in rows.within(self.orientation, start, end) {
+            let row_bounds = row_bounds + Vector::new(bounds.x, bounds.y);
+
+            if !row_bounds.intersects(viewport) {
+                continue;
+            }
+
+            let Some(i) = row.option() else {
+                if let (Row::Header, Some(header)) = (row, self.header) {
+                    // A sticky header is drawn over the options afterwards
+                    if !self.sticky_header {
+                        self.draw_header(
+                            renderer, &style, header, row_bounds, viewport,
+                        );
+                    }
+                } else if let (Row::Empty, Some(message)) =
+                    (row, self.no_results_message)
+                {
+                    renderer.fill_text(
+                        Text {
+                            content: message.to_owned(),
+                            bounds: Size::new(f32::INFINITY, label_height),
+                            size: text_size,
+                            line_height: self.text_line_height,
+                            font: self
+                                .font
+                                .unwrap_or_else(|| renderer.default_font()),
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: self.text_shaping,
+                            wrapping: text::Wrapping::default(),
+                        },
+                        Point::new(
+                            row_bounds.x + self.padding.left,
+                            row_bounds.center_y(),
+                        ),
+                        style.empty_text_color,
+                        *viewport,
+                    );
+                } else if let Row::Divider(index) = row {
+                    self.draw_divider(
+                        renderer,
+                        &style,
+                        self.divider_of(index).unwrap_or_default(),
+                        row_bounds,
+                        viewport,
+                    );
+                } else if row == Row::Close {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row_bounds,
+                            ..renderer::Quad::default()
+                        },
+                        style.close_button_background,
+                    );
+
+                    renderer.fill_text(
+                        Text {
+                            content: self.close_button_label.to_owned(),
+                            bounds: Size::new(f32::INFINITY, label_height),
+                            size: text_size,
+                            line_height: self.text_line_height,
+                            font: self
+                                .font
+                                .unwrap_or_else(|| renderer.default_font()),
+                            horizontal_alignment: alignment::Horizontal::Center,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Advanced,
+                            wrapping: text::Wrapping::None,
+                        },
+                        row_bounds.center(),
+                        style.close_button_text_color,
+                        *viewport,
+                    );
+                }
+
+                continue;
+            };
+            let option = &self.options[i];
+            let label_height = rows.label_heights[i];
+
+            let bounds = match row {
+                Row::Pinned(_) if is_horizontal => Rectangle {
+                    width: row_bounds.width - PINNED_DIVIDER_WIDTH,
+                    ..row_bounds
+                },
+                Row::Pinned(_) => Rectangle {
+                    height: row_bounds.height - PINNED_DIVIDER_HEIGHT,
+                    ..row_bounds
+                },
+                _ => row_bounds,
             };
 
-            if is_selected && !is_disabled {
+            if let Row::Pinned(_) = row {
+                let thickness = style.border.width.max(1.0);
+                let gap = if is_horizontal {
+                    (PINNED_DIVIDER_WIDTH - thickness) / 2.0
+                } else {
+                    (PINNED_DIVIDER_HEIGHT - thickness) / 2.0
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: if is_horizontal {
+                            Rectangle {
+                                x: bounds.x + bounds.width + gap,
+                                y: bounds.y + self.padding.top,
+                                width: thickness,
+                                height: bounds.height - self.padding.vertical(),
+                            }
+                        } else {
+                            Rectangle {
+                                x: bounds.x + self.padding.left,
+                                y: bounds.y + bounds.height + gap,
+                                width: bounds.width - self.padding.horizontal(),
+                                height: thickness,
+                            }
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.border.color,
+                );
+            }
+
+            let is_hovered = hovered_row == Some(row);
+            let keyboard_border = style
+                .keyboard_highlight_border
+                .filter(|_| is_hovered && *self.is_keyboard_navigated);
+
+            // An option hovered with the keyboard is outlined instead of filled
+            let is_selected = is_hovered && keyboard_border.is_none();
+            let is_pressed = is_hovered
+                && self.pressed_option.is_some_and(|press| press.option == i);
+            let is_disabled = self.is_disabled(i);
+            let disabled_style = self
+                .disabled_style
+                .filter(|_| is_disabled)
+                .and_then(|disabled_style| disabled_style(option))
+                .unwrap_or(DisabledStyle {
+                    text_color: style.disabled_text_color,
+                    background: style.disabled_background,
+                });
+
+            if is_pressed && !is_disabled {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + style.row_inset,
+                            width: bounds.width - style.row_inset * 2.0,
+                            ..bounds
+                        },
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.pressed_background,
+                );
+            } else if is_selected && !is_disabled {
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds: Rectangle {
-                            x: bounds.x + style.border.width,
-                            width: bounds.width - style.border.width * 2.0,
+                            x: bounds.x + style.row_inset,
+                            width: bounds.width - style.row_inset * 2.0,
                             ..bounds
                         },
                         border: border::rounded(style.border.radius),
@@ -606,43 +3238,421 @@ where
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds: Rectangle {
-                            x: bounds.x + style.border.width,
-                            width: bounds.width - style.border.width * 2.0,
+                            x: bounds.x + style.row_inset,
+                            width: bounds.width - style.row_inset * 2.0,
                             ..bounds
                         },
                         border: border::rounded(style.border.radius),
                         ..renderer::Quad::default()
                     },
-                    style.disabled_background,
+                    disabled_style.background,
                 );
             }
 
-            renderer.fill_text(
-                Text {
-                    content: option.to_string(),
-                    bounds: Size::new(f32::INFINITY, bounds.height),
-                    size: text_size,
-                    line_height: self.text_line_height,
-                    font: self.font.unwrap_or_else(|| renderer.default_font()),
-                    horizontal_alignment: alignment::Horizontal::Left,
-                    vertical_alignment: alignment::Vertical::Center,
-                    shaping: self.text_shaping,
-                    wrapping: text::Wrapping::default(),
-                },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                if is_disabled {
-                    style.disabled_text_color
-                } else if is_selected {
-                    style.selected_text_color
+            if let Some(border) = keyboard_border.filter(|_| !is_disabled) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + style.row_inset,
+                            width: bounds.width - style.row_inset * 2.0,
+                            ..bounds
+                        },
+                        border,
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            let description = self.description_of(i);
+            let label_center_y = if description.is_some() {
+                bounds.y + self.padding.top + label_height / 2.0
+            } else {
+                bounds.center_y()
+            };
+
+            if let Some(paragraph) = &rows.rich_labels[i] {
+                let position = Point::new(
+                    bounds.x + self.padding.left,
+                    label_center_y - label_height / 2.0,
+                );
+
+                if is_selected && !is_disabled {
+                    // The selected text color replaces the colors of the
+                    // segments, so only the selected label is shaped again
+                    if let Some(paragraph) = self.rich_paragraph(
+                        i,
+                        bounds.width
+                            - self.padding.horizontal()
+                            - self.indicator_width(),
+                        false,
+                        renderer,
+                    ) {
+                        renderer.fill_paragraph(
+                            &paragraph,
+                            position,
+                            style.selected_text_color,
+                            *viewport,
+                        );
+                    }
                 } else {
-                    style.text_color
-                },
-                *viewport,
+                    renderer.fill_paragraph(
+                        paragraph,
+                        position,
+                        if is_disabled {
+                            disabled_style.text_color
+                        } else {
+                            style.text_color
+                        },
+                        *viewport,
+                    );
+                }
+            } else {
+                renderer.fill_text(
+                    Text {
+                        content: option.to_string(),
+                        bounds: Size::new(
+                            if self.text_wrapping.is_some() {
+                                bounds.width
+                                    - self.padding.horizontal()
+                                    - self.indicator_width()
+                            } else {
+                                f32::INFINITY
+                            },
+                            label_height,
+                        ),
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font: self.label_font(i, renderer),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: self.text_wrapping.unwrap_or_default(),
+                    },
+                    Point::new(bounds.x + self.padding.left, label_center_y),
+                    if is_disabled {
+                        disabled_style.text_color
+                    } else if is_selected {
+                        style.selected_text_color
+                    } else {
+                        style.text_color
+                    },
+                    *viewport,
+                );
+            }
+
+            if let Some(shortcut) =
+                self.shortcut.and_then(|shortcut| shortcut(option))
+            {
+                renderer.fill_text(
+                    Text {
+                        content: shortcut,
+                        bounds: Size::new(f32::INFINITY, label_height),
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font: self
+                            .font
+                            .unwrap_or_else(|| renderer.default_font()),
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: text::Wrapping::None,
+                    },
+                    Point::new(
+                        bounds.x + bounds.width
+                            - self.padding.right
+                            - self.indicator_width(),
+                        label_center_y,
+                    ),
+                    if is_disabled {
+                        disabled_style.text_color
+                    } else if is_selected {
+                        style.selected_text_color.scale_alpha(0.8)
+                    } else {
+                        style.shortcut_color
+                    },
+                    *viewport,
+                );
+            }
+
+            let indicator_bounds = Rectangle {
+                x: bounds.x + bounds.width
+                    - self.padding.right
+                    - INDICATOR_SIZE,
+                y: label_center_y - INDICATOR_SIZE / 2.0,
+                width: INDICATOR_SIZE,
+                height: INDICATOR_SIZE,
+            };
+
+            let is_busy =
+                self.row_busy.is_some_and(|row_busy| row_busy(option));
+
+            if let Some(spinner) = self.spinner.filter(|_| is_busy) {
+                let turn = spinner.elapsed.as_secs_f32()
+                    / SPINNER_PERIOD.as_secs_f32();
+                let head = (turn.fract() * SPINNER_DOTS as f32) as usize;
+                let radius = (INDICATOR_SIZE - SPINNER_DOT_SIZE) / 2.0;
+                let center = indicator_bounds.center();
+
+                for dot in 0..SPINNER_DOTS {
+                    let angle = dot as f32 / SPINNER_DOTS as f32
+                        * std::f32::consts::TAU;
+
+                    // Dots fade out behind the head of the spinner
+                    let age = (head + SPINNER_DOTS - dot) % SPINNER_DOTS;
+                    let alpha = 1.0 - age as f32 / SPINNER_DOTS as f32;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: center.x + radius * angle.cos()
+                                    - SPINNER_DOT_SIZE / 2.0,
+                                y: center.y + radius * angle.sin()
+                                    - SPINNER_DOT_SIZE / 2.0,
+                                width: SPINNER_DOT_SIZE,
+                                height: SPINNER_DOT_SIZE,
+                            },
+                            border: border::rounded(SPINNER_DOT_SIZE / 2.0),
+                            ..renderer::Quad::default()
+                        },
+                        if is_selected && !is_disabled {
+                            style.selected_text_color
+                        } else {
+                            style.text_color
+                        }
+                        .scale_alpha(alpha),
+                    );
+                }
+            } else if let Some(color) =
+                self.indicator.and_then(|indicator| indicator(option))
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: indicator_bounds,
+                        border: border::rounded(INDICATOR_SIZE / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    if is_disabled {
+                        color.scale_alpha(0.5)
+                    } else {
+                        color
+                    },
+                );
+            }
+
+            if let Some(description) = description {
+                let description_size = description_text_size(text_size);
+                let description_height = f32::from(
+                    self.text_line_height.to_absolute(description_size),
+                );
+
+                renderer.fill_text(
+                    Text {
+                        content: description,
+                        bounds: Size::new(f32::INFINITY, description_height),
+                        size: description_size,
+                        line_height: self.text_line_height,
+                        font: self
+                            .font
+                            .unwrap_or_else(|| renderer.default_font()),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: text::Wrapping::default(),
+                    },
+                    Point::new(
+                        bounds.x + self.padding.left,
+                        label_center_y
+                            + label_height / 2.0
+                            + description_height / 2.0,
+                    ),
+                    if is_disabled {
+                        disabled_style.text_color
+                    } else if is_selected {
+                        style.selected_text_color.scale_alpha(0.8)
+                    } else {
+                        style.description_color
+                    },
+                    *viewport,
+                );
+            }
+        }
+
+        if let Some((header, header_bounds)) = self
+            .header
+            .zip(self.sticky_header_bounds(rows, bounds, viewport))
+        {
+            renderer.with_layer(*viewport, |renderer| {
+                self.draw_header(
+                    renderer,
+                    &style,
+                    header,
+                    header_bounds,
+                    viewport,
+                );
+            });
+        }
+
+        // The tooltip belongs to the option hovered by the mouse, which may
+        // have been changed since by the keyboard
+        let tooltip = self
+            .hover_tooltip
+            .filter(|tooltip| {
+                tooltip.is_shown && *self.hovered_option == Some(tooltip.option)
+            })
+            .and_then(|tooltip| {
+                let content =
+                    (self.tooltip?)(self.options.get(tooltip.option)?)?;
+
+                Some((tooltip.option, content))
+            });
+
+        if let Some((index, content)) = tooltip {
+            self.draw_tooltip(
+                renderer, &style, rows, index, &content, bounds, viewport,
             );
         }
     }
 }
 
+/// The appearance of a disabled option of a [`Menu`], overriding the
+/// disabled colors of its [`Style`].
+///
+/// Different styles can tell apart why options are disabled, e.g. "coming
+/// soon" rather than "unavailable".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisabledStyle {
+    /// The text [`Color`] of the option.
+    pub text_color: Color,
+    /// The [`Background`] of the option.
+    pub background: Background,
+}
+
+/// The input that selected an option of a [`PickList`].
+///
+/// [`PickList`]: crate::widget::pick_list::PickList
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectSource {
+    /// A click on an option of the [`Menu`].
+    Mouse,
+    /// A tap on an option of the [`Menu`].
+    Touch,
+    /// A key press, either `Enter` in the [`Menu`] or an arrow key on the
+    /// closed field.
+    Keyboard,
+    /// A scroll of the mouse wheel over the closed field.
+    Scroll,
+}
+
+/// The direction in which the options of a [`Menu`] are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// The options are stacked vertically.
+    #[default]
+    Vertical,
+    /// The options are laid out in a single row, scrolling horizontally.
+    Horizontal,
+}
+
+/// When the scrollbar of a [`Menu`] is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollbarPolicy {
+    /// The scrollbar is displayed over the options, only when they overflow.
+    #[default]
+    Auto,
+    /// The space of the scrollbar is always reserved next to the options,
+    /// so that they never shift. Its scroller is only displayed when the
+    /// options overflow.
+    AlwaysOn,
+    /// The scrollbar is never displayed; the options can still be scrolled
+    /// with the wheel.
+    AlwaysOff,
+}
+
+/// A row of a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    /// The static header of the list.
+    Header,
+    /// The placeholder shown when the list has no options.
+    Empty,
+    /// The divider above the option at the given index.
+    Divider(usize),
+    /// The option at the given index.
+    Option(usize),
+    /// The option at the given index, pinned at the top of the list.
+    Pinned(usize),
+    /// The button closing the list, below everything else.
+    Close,
+}
+
+impl Row {
+    /// Get the index of the option displayed in this row, if any
+    fn option(self) -> Option<usize> {
+        match self {
+            Row::Header | Row::Empty | Row::Divider(_) | Row::Close => None,
+            Row::Option(index) | Row::Pinned(index) => Some(index),
+        }
+    }
+}
+
+/// The [`Padding`] of a dense [`Menu`] and [`PickList`].
+///
+/// [`PickList`]: crate::widget::pick_list::PickList
+pub const DENSE_PADDING: Padding = Padding {
+    top: 2.0,
+    bottom: 2.0,
+    right: 6.0,
+    left: 6.0,
+};
+
+/// The text size of a dense [`Menu`] and [`PickList`].
+///
+/// [`PickList`]: crate::widget::pick_list::PickList
+pub const DENSE_TEXT_SIZE: Pixels = Pixels(13.0);
+
+/// The text [`text::LineHeight`] of a dense [`Menu`] and [`PickList`].
+///
+/// [`PickList`]: crate::widget::pick_list::PickList
+pub const DENSE_LINE_HEIGHT: text::LineHeight = text::LineHeight::Relative(1.2);
+
+/// The minimum space between the label of an option and its shortcut hint.
+pub(crate) const SHORTCUT_SPACING: f32 = 10.0;
+
+/// The diameter of the status indicator of an option.
+const INDICATOR_SIZE: f32 = 8.0;
+
+/// The minimum space between the text of an option and its status indicator.
+const INDICATOR_SPACING: f32 = 8.0;
+
+/// The width reserved in every row of a [`Menu`] with status indicators.
+pub(crate) const INDICATOR_WIDTH: f32 = INDICATOR_SIZE + INDICATOR_SPACING;
+
+/// The default label of the close button of a [`Menu`].
+pub const DEFAULT_CLOSE_BUTTON_LABEL: &str = "✕ Close";
+
+/// The height of a divider without text.
+const DIVIDER_HEIGHT: f32 = 9.0;
+
+/// The width of a divider between options laid out horizontally.
+const DIVIDER_WIDTH: f32 = 9.0;
+
+/// The space between the text of a divider and its line.
+const DIVIDER_LABEL_SPACING: f32 = 6.0;
+
+/// The height of the space separating a pinned option from the rest.
+const PINNED_DIVIDER_HEIGHT: f32 = 5.0;
+
+/// The width of the space separating a pinned option from the rest when
+/// options are laid out horizontally.
+const PINNED_DIVIDER_WIDTH: f32 = 5.0;
+
+/// The text size of option descriptions, relative to the text size of their
+/// label.
+pub(crate) fn description_text_size(text_size: Pixels) -> Pixels {
+    text_size * 0.8
+}
+
 impl<'a, 'b, T, Message, Theme, Renderer>
     From<List<'a, 'b, T, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
@@ -665,16 +3675,49 @@ pub struct Style {
     pub background: Background,
     /// The [`Border`] of the menu.
     pub border: Border,
+    /// The [`Shadow`] cast by the menu, if any.
+    pub shadow: Option<Shadow>,
     /// The text [`Color`] of the menu.
     pub text_color: Color,
     /// The text [`Color`] of a selected option in the menu.
     pub selected_text_color: Color,
     /// The background [`Color`] of a selected option in the menu.
     pub selected_background: Background,
+    /// The background [`Color`] of a pressed option in the menu.
+    pub pressed_background: Background,
     /// The text [`Color`] of a disabled option in the menu.
     pub disabled_text_color: Color,
     /// The background [`Color`] of a disabled option in the menu.
     pub disabled_background: Background,
+    /// The text [`Color`] of the description of an option in the menu.
+    pub description_color: Color,
+    /// The text [`Color`] of the shortcut hint of an option in the menu.
+    pub shortcut_color: Color,
+    /// The text [`Color`] of the header of the menu.
+    pub header_text_color: Color,
+    /// The [`Background`] of the header of the menu.
+    pub header_background: Background,
+    /// The text [`Color`] of the message shown when the menu has no options.
+    pub empty_text_color: Color,
+    /// The [`Color`] of the line of a divider in the menu.
+    pub divider_color: Color,
+    /// The text [`Color`] of a divider in the menu.
+    pub divider_text_color: Color,
+    /// The text [`Color`] of the tooltip of an option in the menu.
+    pub tooltip_text_color: Color,
+    /// The [`Background`] of the tooltip of an option in the menu.
+    pub tooltip_background: Background,
+    /// The text [`Color`] of the close button of the menu.
+    pub close_button_text_color: Color,
+    /// The [`Background`] of the close button of the menu.
+    pub close_button_background: Background,
+    /// The [`Border`] outlining the option hovered with the keyboard, if
+    /// any, in place of the `selected_background`.
+    pub keyboard_highlight_border: Option<Border>,
+    /// The horizontal inset of the backgrounds of the rows of the menu.
+    ///
+    /// Set it to `0.0` to draw row backgrounds edge-to-edge.
+    pub row_inset: f32,
 }
 
 /// The theme catalog of a [`Menu`].
@@ -720,9 +3763,15 @@ pub fn default(theme: &Theme) -> Style {
             radius: 0.0.into(),
             color: palette.background.strong.color,
         },
+        shadow: Some(Shadow {
+            color: Color::BLACK.scale_alpha(0.15),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        }),
         text_color: palette.background.weak.text,
         selected_text_color: palette.primary.strong.text,
         selected_background: palette.primary.strong.color.into(),
+        pressed_background: palette.primary.base.color.into(),
         disabled_text_color: palette.background.weak.text.scale_alpha(0.5),
         disabled_background: palette
             .background
@@ -730,5 +3779,18 @@ pub fn default(theme: &Theme) -> Style {
             .color
             .scale_alpha(0.5)
             .into(),
+        description_color: palette.background.weak.text.scale_alpha(0.7),
+        shortcut_color: palette.background.weak.text.scale_alpha(0.6),
+        header_text_color: palette.background.strong.text,
+        header_background: palette.background.strong.color.into(),
+        empty_text_color: palette.background.weak.text.scale_alpha(0.6),
+        divider_color: palette.background.strong.color,
+        divider_text_color: palette.background.weak.text.scale_alpha(0.6),
+        tooltip_text_color: palette.background.strong.text,
+        tooltip_background: palette.background.strong.color.into(),
+        close_button_text_color: palette.background.weak.text,
+        close_button_background: palette.background.base.color.into(),
+        keyboard_highlight_border: None,
+        row_inset: 1.0,
     }
 }
@@ -2,10 +2,8 @@
 //!
 //! # Example
 //! ```no_run
-//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
-//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
-//! #
-//! use iced::widget::pick_list;
+//! use iced::Element;
+//! use sweeten::widget::pick_list;
 //!
 //! struct State {
 //!    favorite: Option<Fruit>,
@@ -34,6 +32,12 @@
 //!
 //!     pick_list(
 //!         fruits,
+//!         Some(|fruits: &[Fruit]| {
+//!             fruits
+//!                 .iter()
+//!                 .map(|fruit| matches!(fruit, Fruit::Tomato))
+//!                 .collect()
+//!         }),
 //!         state.favorite,
 //!         Message::FruitSelected,
 //!     )
@@ -83,33 +87,42 @@
 // COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
-use iced::advanced::text::{self, paragraph, Text};
+use iced::advanced::text::{self, paragraph, Paragraph as _, Text};
+use iced::advanced::widget::operation::{self, Operation};
 use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::widget::Id;
 use iced::advanced::{
     layout, mouse, overlay, renderer, Clipboard, Layout, Shell, Widget,
 };
 use iced::alignment;
+use iced::border;
 use iced::event::{self, Event};
 use iced::keyboard;
+use iced::time::{Duration, Instant};
 use iced::touch;
+use iced::window;
 use iced::{
     Background, Border, Color, Element, Length, Padding, Pixels, Point,
-    Rectangle, Size, Theme, Vector,
+    Rectangle, Shadow, Size, Theme, Vector,
 };
 
+use std::any::Any;
 use std::borrow::Borrow;
 use std::f32;
 
+use crate::widget::mouse_area::PIXELS_PER_LINE;
 use crate::widget::overlay::menu::{self, Menu};
 
+pub use crate::widget::overlay::menu::{
+    SelectSource, DENSE_LINE_HEIGHT, DENSE_PADDING, DENSE_TEXT_SIZE,
+};
+
 /// A widget for selecting a single value from a list of options.
 ///
 /// # Example
 /// ```no_run
-/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
-/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
-/// #
-/// use iced::widget::pick_list;
+/// use iced::Element;
+/// use sweeten::widget::pick_list;
 ///
 /// struct State {
 ///    favorite: Option<Fruit>,
@@ -138,6 +151,12 @@ use crate::widget::overlay::menu::{self, Menu};
 ///
 ///     pick_list(
 ///         fruits,
+///         Some(|fruits: &[Fruit]| {
+///             fruits
+///                 .iter()
+///                 .map(|fruit| matches!(fruit, Fruit::Tomato))
+///                 .collect()
+///         }),
 ///         state.favorite,
 ///         Message::FruitSelected,
 ///     )
@@ -182,19 +201,88 @@ pub struct PickList<
     Renderer: text::Renderer,
 {
     on_select: Box<dyn Fn(T) -> Message + 'a>,
+    on_select_with: Option<Box<dyn Fn(T, SelectSource) -> Message + 'a>>,
+    on_reselect: ReselectBehavior<Message>,
     on_open: Option<Message>,
+    on_open_with: Option<Box<dyn Fn(Rectangle) -> Message + 'a>>,
     on_close: Option<Message>,
+    on_flip: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    on_clear: Option<Message>,
+    on_menu_scroll: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_scroll_start: Option<Message>,
+    on_disabled_hover: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_option_double_click: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_menu_cursor: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_modifiers: Option<Box<dyn Fn(keyboard::Modifiers) -> Message + 'a>>,
+    on_menu_key: Option<Box<dyn Fn(keyboard::Key) -> Option<Message> + 'a>>,
+    on_nav_edge: Option<Box<dyn Fn(Edge) -> Message + 'a>>,
+    keyboard_priority: Option<Duration>,
+    on_options_changed: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_clip: Option<Message>,
     options: L,
     disabled: Option<Box<dyn Fn(&[T]) -> Vec<bool> + 'a>>,
+    labeled_dividers: Option<Box<dyn Fn(&[T]) -> Vec<Option<String>> + 'a>>,
+    option_description: Option<Box<dyn Fn(&T) -> Option<String> + 'a>>,
+    option_shortcut: Option<Box<dyn Fn(&T) -> Option<String> + 'a>>,
+    rich_label: Option<Box<dyn Fn(&T) -> Vec<(String, Color)> + 'a>>,
+    option_indicator: Option<Box<dyn Fn(&T) -> Option<Color> + 'a>>,
+    tooltip: Option<Box<dyn Fn(&T) -> Option<String> + 'a>>,
+    option_font: Option<Box<dyn Fn(&T) -> Option<Renderer::Font> + 'a>>,
+    row_busy: Option<Box<dyn Fn(&T) -> bool + 'a>>,
+    disabled_style: Option<Box<dyn Fn(&T) -> Option<menu::DisabledStyle> + 'a>>,
+    suffix: Option<Box<dyn Fn(Option<&T>) -> Option<String> + 'a>>,
     placeholder: Option<String>,
+    placeholder_icon: Option<Icon<Renderer::Font>>,
     selected: Option<V>,
+    selected_index: Option<usize>,
     width: Length,
     padding: Padding,
+    menu_padding: Option<Padding>,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
+    menu_line_height: Option<text::LineHeight>,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
+    handle_spacing: f32,
+    flip_handle: bool,
+    handle_with:
+        Option<Box<dyn Fn(Status, bool) -> Option<Icon<Renderer::Font>> + 'a>>,
+    truncate: bool,
+    width_sample: Option<usize>,
+    marquee_on_hover: bool,
+    icon_only: bool,
+    selected_badge: bool,
+    corner_radius: Option<border::Radius>,
+    menu_border_from_field: bool,
+    arrows_change_closed: bool,
+    name: Option<String>,
+    id: Option<Id>,
+    initially_open: bool,
+    restore_hover_on_open: bool,
+    trap_scroll: bool,
+    menu_resizable: bool,
+    snap_rows: bool,
+    overscroll: bool,
+    selected_first: bool,
+    reverse: bool,
+    menu_header: Option<String>,
+    sticky_header: bool,
+    menu_close_button: bool,
+    menu_close_button_label: Option<String>,
+    no_results_message: Option<String>,
+    menu_min_width: Option<f32>,
+    menu_alignment: alignment::Horizontal,
+    open_on_hover: Option<Duration>,
+    text_wrapping: Option<text::Wrapping>,
+    orientation: menu::Orientation,
+    menu_scrollbar: menu::ScrollbarPolicy,
+    columns: usize,
+    menu_scroll: bool,
+    scroll_up: bool,
+    scroll_down: bool,
+    scroll_sensitivity: Option<f32>,
+    scroll_momentum: bool,
     class: <Theme as Catalog>::Class<'a>,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
 }
@@ -219,19 +307,87 @@ where
     ) -> Self {
         Self {
             on_select: Box::new(on_select),
+            on_select_with: None,
+            on_reselect: ReselectBehavior::Reemit,
             disabled: disabled.map(|f| Box::new(f) as _),
+            labeled_dividers: None,
             on_open: None,
+            on_open_with: None,
             on_close: None,
+            on_flip: None,
+            on_clear: None,
+            on_menu_scroll: None,
+            on_scroll_start: None,
+            on_disabled_hover: None,
+            on_option_double_click: None,
+            on_menu_cursor: None,
+            on_modifiers: None,
+            on_menu_key: None,
+            on_nav_edge: None,
+            keyboard_priority: None,
+            on_options_changed: None,
+            on_clip: None,
             options,
+            option_description: None,
+            option_shortcut: None,
+            rich_label: None,
+            option_indicator: None,
+            tooltip: None,
+            option_font: None,
+            row_busy: None,
+            disabled_style: None,
+            suffix: None,
             placeholder: None,
+            placeholder_icon: None,
             selected,
+            selected_index: None,
             width: Length::Shrink,
             padding: DEFAULT_PADDING,
+            menu_padding: None,
             text_size: None,
             text_line_height: text::LineHeight::default(),
+            menu_line_height: None,
             text_shaping: text::Shaping::default(),
             font: None,
             handle: Handle::default(),
+            handle_spacing: 0.0,
+            flip_handle: false,
+            handle_with: None,
+            truncate: false,
+            width_sample: None,
+            marquee_on_hover: false,
+            icon_only: false,
+            selected_badge: false,
+            corner_radius: None,
+            menu_border_from_field: false,
+            arrows_change_closed: false,
+            name: None,
+            id: None,
+            initially_open: false,
+            restore_hover_on_open: false,
+            trap_scroll: false,
+            menu_resizable: false,
+            snap_rows: false,
+            overscroll: false,
+            selected_first: false,
+            reverse: false,
+            menu_header: None,
+            sticky_header: false,
+            menu_close_button: false,
+            menu_close_button_label: None,
+            no_results_message: None,
+            menu_min_width: None,
+            menu_alignment: alignment::Horizontal::Left,
+            open_on_hover: None,
+            text_wrapping: None,
+            orientation: menu::Orientation::Vertical,
+            menu_scrollbar: menu::ScrollbarPolicy::Auto,
+            columns: 1,
+            menu_scroll: true,
+            scroll_up: true,
+            scroll_down: true,
+            scroll_sensitivity: None,
+            scroll_momentum: false,
             class: <Theme as Catalog>::default(),
             menu_class: <Theme as Catalog>::default_menu(),
         }
@@ -243,6 +399,159 @@ where
         self
     }
 
+    /// Sets the [`Icon`] shown before the placeholder of the [`PickList`].
+    ///
+    /// The [`Icon`] is only shown while nothing is selected.
+    pub fn placeholder_icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.placeholder_icon = Some(icon);
+        self
+    }
+
+    /// Sets the closure producing an optional description for each option.
+    ///
+    /// Descriptions are drawn as a smaller, dimmer second line under the
+    /// label of their option in the [`Menu`].
+    pub fn option_description(
+        mut self,
+        option_description: impl Fn(&T) -> Option<String> + 'a,
+    ) -> Self {
+        self.option_description = Some(Box::new(option_description));
+        self
+    }
+
+    /// Sets the closure producing an optional shortcut hint for each option.
+    ///
+    /// Shortcuts are display-only: they are drawn right-aligned in their row
+    /// of the [`Menu`], but handling the keys is up to the application.
+    pub fn option_shortcut(
+        mut self,
+        option_shortcut: impl Fn(&T) -> Option<String> + 'a,
+    ) -> Self {
+        self.option_shortcut = Some(Box::new(option_shortcut));
+        self
+    }
+
+    /// Sets the closure producing the styled segments of the label of each
+    /// option in the [`Menu`].
+    ///
+    /// The segments are drawn one after the other, each in its own
+    /// [`Color`] unless the option is disabled or selected, and should spell
+    /// out the label of the option, since the [`PickList`] is still measured
+    /// with its `to_string`. Options producing no segments are drawn as plain
+    /// text.
+    pub fn rich_label(
+        mut self,
+        rich_label: impl Fn(&T) -> Vec<(String, Color)> + 'a,
+    ) -> Self {
+        self.rich_label = Some(Box::new(rich_label));
+        self
+    }
+
+    /// Sets the closure producing the dividers of the open [`Menu`], given
+    /// the options, with one entry per option.
+    ///
+    /// A divider is drawn above every option with a `Some` entry, with its
+    /// text centered on the line unless it is empty. Dividers cannot be
+    /// hovered nor selected.
+    pub fn labeled_dividers(
+        mut self,
+        labeled_dividers: impl Fn(&[T]) -> Vec<Option<String>> + 'a,
+    ) -> Self {
+        self.labeled_dividers = Some(Box::new(labeled_dividers));
+        self
+    }
+
+    /// Sets the closure producing the color of an optional status indicator
+    /// for each option.
+    ///
+    /// Indicators are drawn as a small dot at the right edge of their row of
+    /// the [`Menu`], such as to show the availability of each option.
+    pub fn option_indicator(
+        mut self,
+        option_indicator: impl Fn(&T) -> Option<Color> + 'a,
+    ) -> Self {
+        self.option_indicator = Some(Box::new(option_indicator));
+        self
+    }
+
+    /// Sets the closure producing an optional tooltip for each option.
+    ///
+    /// The tooltip of an option is shown in the open [`Menu`] once the
+    /// option has been hovered for a short delay, whether it is enabled or
+    /// not.
+    pub fn tooltip(
+        mut self,
+        tooltip: impl Fn(&T) -> Option<String> + 'a,
+    ) -> Self {
+        self.tooltip = Some(Box::new(tooltip));
+        self
+    }
+
+    /// Sets the closure producing the font of each option in the open
+    /// [`Menu`], which is also used for the selected option in the closed
+    /// [`PickList`].
+    ///
+    /// Options for which it returns `None` use the font of the [`PickList`].
+    pub fn option_font(
+        mut self,
+        option_font: impl Fn(&T) -> Option<Renderer::Font> + 'a,
+    ) -> Self {
+        self.option_font = Some(Box::new(option_font));
+        self
+    }
+
+    /// Sets the closure deciding which options are busy, e.g. while their
+    /// details are being fetched.
+    ///
+    /// Busy options show an animated spinner at the right edge of their row
+    /// of the open [`Menu`], which is redrawn continuously while any option
+    /// is busy.
+    pub fn row_busy(mut self, row_busy: impl Fn(&T) -> bool + 'a) -> Self {
+        self.row_busy = Some(Box::new(row_busy));
+        self
+    }
+
+    /// Sets the closure producing the [`menu::DisabledStyle`] of each
+    /// disabled option of the open [`Menu`].
+    ///
+    /// Options disabled for different reasons can be styled differently.
+    /// The disabled colors of the menu style are used when it returns
+    /// `None`.
+    pub fn disabled_style(
+        mut self,
+        disabled_style: impl Fn(&T) -> Option<menu::DisabledStyle> + 'a,
+    ) -> Self {
+        self.disabled_style = Some(Box::new(disabled_style));
+        self
+    }
+
+    /// Sets the closure producing a suffix for the selected option, or for
+    /// the placeholder when nothing is selected.
+    ///
+    /// The suffix is drawn right before the [`Handle`] using the
+    /// `suffix_color` of the [`Style`]. It is useful to show units or badges,
+    /// like "3 items".
+    pub fn suffix(
+        mut self,
+        suffix: impl Fn(Option<&T>) -> Option<String> + 'a,
+    ) -> Self {
+        self.suffix = Some(Box::new(suffix));
+        self
+    }
+
+    /// Sets the selected option of the [`PickList`] by its index in the list
+    /// of options, replacing any selected value.
+    ///
+    /// An index out of bounds leaves the [`PickList`] without a selection.
+    pub fn with_selected_index(
+        mut self,
+        selected_index: Option<usize>,
+    ) -> Self {
+        self.selected = None;
+        self.selected_index = selected_index;
+        self
+    }
+
     /// Sets the width of the [`PickList`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -255,6 +564,29 @@ where
         self
     }
 
+    /// Sets the [`Padding`] of the options of the open [`Menu`].
+    ///
+    /// By default, the options have the same [`Padding`] as the
+    /// [`PickList`].
+    pub fn menu_padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.menu_padding = Some(padding.into());
+        self
+    }
+
+    /// Applies the dense preset to the [`PickList`] and its [`Menu`], with
+    /// smaller padding and text for data-heavy interfaces.
+    ///
+    /// This sets the padding to [`DENSE_PADDING`], the text size to
+    /// [`DENSE_TEXT_SIZE`], and the text line height to
+    /// [`DENSE_LINE_HEIGHT`].
+    pub fn dense(mut self) -> Self {
+        self.menu_line_height = Some(DENSE_LINE_HEIGHT);
+
+        self.padding(DENSE_PADDING)
+            .text_size(DENSE_TEXT_SIZE)
+            .text_line_height(DENSE_LINE_HEIGHT)
+    }
+
     /// Sets the text size of the [`PickList`].
     pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
         self.text_size = Some(size.into());
@@ -276,6 +608,55 @@ where
         self
     }
 
+    /// Sets the [`menu::Orientation`] of the options of the [`Menu`].
+    ///
+    /// A [`menu::Orientation::Horizontal`] menu lays out its options in a
+    /// single row that scrolls sideways, e.g. for toolbar selectors.
+    pub fn orientation(mut self, orientation: menu::Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the number of columns of the grid the options of the [`Menu`]
+    /// are laid out in.
+    ///
+    /// Dense option sets, like emoji or icons, fit better in a grid. While
+    /// the [`Menu`] is open, the vertical arrow keys move across rows and the
+    /// horizontal ones across columns. A single column is used by default.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Sets whether the options of the [`Menu`] can be scrolled.
+    ///
+    /// When disabled, the [`Menu`] is sized to its content and opens in
+    /// whichever direction has the most space. Scrolling is enabled by
+    /// default.
+    pub fn menu_scroll(mut self, menu_scroll: bool) -> Self {
+        self.menu_scroll = menu_scroll;
+        self
+    }
+
+    /// Sets when the scrollbar of the [`Menu`] is displayed.
+    ///
+    /// By default, it is only displayed when the options overflow.
+    pub fn menu_scrollbar(mut self, policy: menu::ScrollbarPolicy) -> Self {
+        self.menu_scrollbar = policy;
+        self
+    }
+
+    /// Sets the [`text::Wrapping`] strategy of the [`PickList`] and the
+    /// options of its [`Menu`].
+    ///
+    /// By default, every option of the [`Menu`] is laid out on a single
+    /// line. Wrapping options take as many lines as they need, making their
+    /// rows taller.
+    pub fn text_wrapping(mut self, wrapping: text::Wrapping) -> Self {
+        self.text_wrapping = Some(wrapping);
+        self
+    }
+
     /// Sets the font of the [`PickList`].
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
         self.font = Some(font.into());
@@ -288,178 +669,1418 @@ where
         self
     }
 
-    /// Sets the message that will be produced when the [`PickList`] is opened.
-    pub fn on_open(mut self, on_open: Message) -> Self {
-        self.on_open = Some(on_open);
+    /// Sets the closure producing the icon of the handle of the [`PickList`],
+    /// given its [`Status`] and whether its [`Menu`] is open.
+    ///
+    /// When set, it replaces the [`Handle`], e.g. to show a spinner while the
+    /// options are loading. Returning `None` hides the handle.
+    pub fn handle_with(
+        mut self,
+        handle_with: impl Fn(Status, bool) -> Option<Icon<Renderer::Font>> + 'a,
+    ) -> Self {
+        self.handle_with = Some(Box::new(handle_with));
         self
     }
 
-    /// Sets the message that will be produced when the [`PickList`] is closed.
-    pub fn on_close(mut self, on_close: Message) -> Self {
-        self.on_close = Some(on_close);
+    /// Sets the extra space between the [`Handle`] and the right edge of the
+    /// [`PickList`], on top of its padding.
+    ///
+    /// This nudges the [`Handle`] inwards without changing the padding of
+    /// the label. It is `0.0` by default.
+    pub fn handle_spacing(mut self, spacing: f32) -> Self {
+        self.handle_spacing = spacing;
         self
     }
 
-    /// Sets the style of the [`PickList`].
-    #[must_use]
-    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
-    where
-        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
-    {
-        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+    /// Sets whether the [`Handle::Arrow`] points up (▲) while the [`Menu`] is
+    /// open.
+    ///
+    /// Both arrows are then drawn with the default font. This is `false` by
+    /// default.
+    pub fn flip_handle(mut self, flip_handle: bool) -> Self {
+        self.flip_handle = flip_handle;
         self
     }
 
-    /// Sets the style of the [`Menu`].
-    #[must_use]
-    pub fn menu_style(
-        mut self,
-        style: impl Fn(&Theme) -> menu::Style + 'a,
-    ) -> Self
-    where
-        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
-    {
-        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+    /// Sets how many of the first options are measured to find the width of
+    /// a [`PickList`] with a [`Length::Shrink`] width.
+    ///
+    /// Measuring every option is slow for huge lists. With a sample, only
+    /// the first options and the selected one are measured, so a longer
+    /// option further down may be clipped. Every option is measured by
+    /// default.
+    pub fn width_sample(mut self, sample: usize) -> Self {
+        self.width_sample = Some(sample);
         self
     }
 
-    /// Sets the style class of the [`PickList`].
-    #[must_use]
-    pub fn class(
-        mut self,
-        class: impl Into<<Theme as Catalog>::Class<'a>>,
-    ) -> Self {
-        self.class = class.into();
+    /// Sets whether the label in the closed [`PickList`] should be truncated
+    /// with an ellipsis when it does not fit next to the [`Handle`].
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
         self
     }
 
-    /// Sets the style class of the [`Menu`].
-    #[must_use]
-    pub fn menu_class(
+    /// Sets whether a truncated label scrolls horizontally while the closed
+    /// [`PickList`] is hovered, revealing its full text.
+    ///
+    /// It only has an effect when the label is [`truncate`](Self::truncate)d.
+    pub fn marquee_on_hover(mut self, marquee_on_hover: bool) -> Self {
+        self.marquee_on_hover = marquee_on_hover;
+        self
+    }
+
+    /// Sets whether the closed [`PickList`] only shows its [`Handle`],
+    /// centered, without the label of the selected option.
+    ///
+    /// The field then shrinks to fit the [`Handle`], which suits compact
+    /// toolbars. The open [`Menu`] still shows the labels of every option.
+    pub fn icon_only(mut self, icon_only: bool) -> Self {
+        self.icon_only = icon_only;
+        self
+    }
+
+    /// Sets the radius of the corners of both the field and the open [`Menu`]
+    /// of the [`PickList`], overriding the ones of their styles.
+    pub fn corner_radius(
         mut self,
-        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+        corner_radius: impl Into<border::Radius>,
     ) -> Self {
-        self.menu_class = class.into();
+        self.corner_radius = Some(corner_radius.into());
         self
     }
-}
 
-impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for PickList<'a, T, L, V, Message, Theme, Renderer>
-where
-    T: Clone + ToString + PartialEq + 'a,
-    L: Borrow<[T]>,
-    V: Borrow<T>,
-    Message: Clone + 'a,
-    Theme: Catalog + 'a,
-    Renderer: text::Renderer + 'a,
-{
-    fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State<Renderer::Paragraph>>()
+    /// Sets whether the open [`Menu`] takes the width and color of the
+    /// border of the [`PickList`], as styled while it is open, for a
+    /// seamless look.
+    pub fn menu_border_from_field(mut self, from_field: bool) -> Self {
+        self.menu_border_from_field = from_field;
+        self
     }
 
-    fn state(&self) -> tree::State {
-        tree::State::new(State::<Renderer::Paragraph>::new())
+    /// Sets whether the selected option is displayed as a badge, i.e. with a
+    /// rounded background behind its label, in the closed [`PickList`].
+    ///
+    /// The placeholder is never displayed as a badge.
+    pub fn selected_badge(mut self, selected_badge: bool) -> Self {
+        self.selected_badge = selected_badge;
+        self
     }
 
-    fn size(&self) -> Size<Length> {
-        Size {
-            width: self.width,
-            height: Length::Shrink,
-        }
+    /// Sets whether the Up and Down arrow keys change the selected option
+    /// while the [`PickList`] is focused and closed.
+    ///
+    /// The selection moves to the previous or next enabled option, producing
+    /// the `on_select` message without opening the [`Menu`]. While the
+    /// [`Menu`] is open, arrow keys move its highlight instead.
+    ///
+    /// A [`PickList`] gains focus when pressed and loses it when a press
+    /// happens elsewhere. It can also be focused with the focus operations,
+    /// like [`focus_next`](operation::focusable::focus_next).
+    pub fn arrows_change_closed(mut self, arrows_change_closed: bool) -> Self {
+        self.arrows_change_closed = arrows_change_closed;
+        self
     }
 
-    fn layout(
-        &self,
-        tree: &mut Tree,
-        renderer: &Renderer,
-        limits: &layout::Limits,
-    ) -> layout::Node {
-        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+    /// Sets the [`Id`] of the [`PickList`], so that operations like
+    /// [`scroll_to`] can target it.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
 
-        let font = self.font.unwrap_or_else(|| renderer.default_font());
-        let text_size =
-            self.text_size.unwrap_or_else(|| renderer.default_size());
-        let options = self.options.borrow();
+    /// Sets the accessible name of the [`PickList`].
+    ///
+    /// The name is exposed to widget operations as a [`Name`], which allows
+    /// finding the [`PickList`] with operations like [`exists`].
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
 
-        state.options.resize_with(options.len(), Default::default);
+    /// Sets whether the [`PickList`] should be open when it is first
+    /// rendered.
+    ///
+    /// The `on_open` message is produced once for this programmatic open, on
+    /// the first event the [`PickList`] receives.
+    pub fn initially_open(mut self, initially_open: bool) -> Self {
+        self.initially_open = initially_open;
+        self
+    }
 
-        let option_text = Text {
-            content: "",
-            bounds: Size::new(
-                f32::INFINITY,
-                self.text_line_height.to_absolute(text_size).into(),
-            ),
-            size: text_size,
-            line_height: self.text_line_height,
-            font,
-            horizontal_alignment: alignment::Horizontal::Left,
-            vertical_alignment: alignment::Vertical::Center,
-            shaping: self.text_shaping,
-            wrapping: text::Wrapping::default(),
-        };
+    /// Sets whether the [`Menu`] should highlight the option that was last
+    /// hovered when it closed, instead of the selected option, once it opens
+    /// again.
+    ///
+    /// The remembered option is clamped to the last one if the options have
+    /// shrunk in between. This is `false` by default.
+    pub fn restore_hover_on_open(mut self, restore: bool) -> Self {
+        self.restore_hover_on_open = restore;
+        self
+    }
 
-        for (option, paragraph) in options.iter().zip(state.options.iter_mut())
-        {
-            let label = option.to_string();
+    /// Sets whether wheel events over the open [`Menu`] should always be
+    /// captured, preventing them from scrolling the widgets behind it once
+    /// the [`Menu`] reaches the end of its options.
+    ///
+    /// This is `false` by default.
+    pub fn trap_scroll(mut self, trap_scroll: bool) -> Self {
+        self.trap_scroll = trap_scroll;
+        self
+    }
 
-            paragraph.update(Text {
-                content: &label,
-                ..option_text
-            });
-        }
+    /// Sets whether the open [`Menu`] can be widened by dragging its right
+    /// edge.
+    ///
+    /// The chosen width is remembered while the [`PickList`] stays in the
+    /// widget tree. This is `false` by default.
+    pub fn menu_resizable(mut self, resizable: bool) -> Self {
+        self.menu_resizable = resizable;
+        self
+    }
 
-        if let Some(placeholder) = &self.placeholder {
-            state.placeholder.update(Text {
-                content: placeholder,
-                ..option_text
-            });
-        }
+    /// Sets whether the open [`Menu`] snaps to the nearest option once it
+    /// stops scrolling, so that no option is cut off at its top.
+    ///
+    /// It has no effect on a horizontal [`Menu`]. This is `false` by
+    /// default.
+    pub fn snap_rows(mut self, snap_rows: bool) -> Self {
+        self.snap_rows = snap_rows;
+        self
+    }
 
-        let max_width = match self.width {
-            Length::Shrink => {
-                let labels_width =
-                    state.options.iter().fold(0.0, |width, paragraph| {
-                        f32::max(width, paragraph.min_width())
-                    });
+    /// Sets whether the open [`Menu`] bounces when its options are scrolled
+    /// past their start or end with the mouse wheel.
+    ///
+    /// It has no effect on a horizontal [`Menu`]. This is `false` by
+    /// default.
+    pub fn overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
 
-                labels_width.max(
-                    self.placeholder
-                        .as_ref()
-                        .map(|_| state.placeholder.min_width())
-                        .unwrap_or(0.0),
-                )
-            }
-            _ => 0.0,
-        };
+    /// Sets whether the selected option should also be shown as the first
+    /// row of the open [`Menu`], above a divider, so it is always within
+    /// reach.
+    pub fn selected_first(mut self, selected_first: bool) -> Self {
+        self.selected_first = selected_first;
+        self
+    }
 
-        let size = {
-            let intrinsic = Size::new(
-                max_width + text_size.0 + self.padding.left,
-                f32::from(self.text_line_height.to_absolute(text_size)),
-            );
+    /// Sets whether the open [`Menu`] displays the options in reverse order,
+    /// e.g. to show the newest of a list of recent items first.
+    ///
+    /// The options themselves are left untouched, while navigating with the
+    /// arrow keys, the wheel and type-ahead follows the displayed order.
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
 
-            limits
-                .width(self.width)
-                .shrink(self.padding)
+    /// Sets a static title displayed as the first row of the open [`Menu`].
+    ///
+    /// The header cannot be hovered nor selected.
+    pub fn menu_header(mut self, header: impl Into<String>) -> Self {
+        self.menu_header = Some(header.into());
+        self
+    }
+
+    /// Sets whether the [`menu_header`](Self::menu_header) sticks to the top
+    /// of the open [`Menu`] while its options are scrolled.
+    ///
+    /// It has no effect on a horizontal [`Menu`].
+    pub fn sticky_header(mut self, sticky_header: bool) -> Self {
+        self.sticky_header = sticky_header;
+        self
+    }
+
+    /// Sets whether a close button is displayed as the last row of the open
+    /// [`Menu`], which is easier to hit than the outside of the [`Menu`] on
+    /// touch screens.
+    ///
+    /// Pressing it closes the [`Menu`] without selecting anything, producing
+    /// the [`on_close`](Self::on_close) message. This is `false` by default.
+    pub fn menu_close_button(mut self, close_button: bool) -> Self {
+        self.menu_close_button = close_button;
+        self
+    }
+
+    /// Sets the label of the close button of the open [`Menu`].
+    ///
+    /// It is [`menu::DEFAULT_CLOSE_BUTTON_LABEL`] by default.
+    pub fn menu_close_button_label(mut self, label: impl Into<String>) -> Self {
+        self.menu_close_button_label = Some(label.into());
+        self
+    }
+
+    /// Sets the message displayed in the open [`Menu`] when there are no
+    /// options to choose from.
+    pub fn no_results_message(mut self, message: impl Into<String>) -> Self {
+        self.no_results_message = Some(message.into());
+        self
+    }
+
+    /// Sets the minimum width of the open [`Menu`].
+    ///
+    /// By default, the [`Menu`] matches the width of the [`PickList`]. A
+    /// wider [`Menu`] is moved to the left when it would otherwise overflow
+    /// the window.
+    pub fn menu_min_width(mut self, min_width: f32) -> Self {
+        self.menu_min_width = Some(min_width);
+        self
+    }
+
+    /// Sets the horizontal alignment of the open [`Menu`] relative to the
+    /// [`PickList`].
+    ///
+    /// A [`Menu`] wider than the [`PickList`] can be centered over it, or
+    /// aligned to its right edge. It is left-aligned by default.
+    pub fn menu_alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.menu_alignment = alignment;
+        self
+    }
+
+    /// Makes the [`PickList`] open once the cursor has dwelled over it for
+    /// the given delay, in addition to opening on click.
+    ///
+    /// A [`Menu`] opened this way closes by itself when the cursor leaves
+    /// both the [`PickList`] and the [`Menu`] for longer than a short grace
+    /// period, so that the cursor can travel between them.
+    pub fn open_on_hover(mut self, delay: Duration) -> Self {
+        self.open_on_hover = Some(delay);
+        self
+    }
+
+    /// Sets which directions of the wheel can change the selected option
+    /// while the command key is held over the [`PickList`].
+    ///
+    /// Scrolling up selects the previous option and scrolling down selects
+    /// the next one. Both directions are enabled by default.
+    pub fn scroll_directions(mut self, up: bool, down: bool) -> Self {
+        self.scroll_up = up;
+        self.scroll_down = down;
+        self
+    }
+
+    /// Sets the wheel sensitivity used when the command key is held over
+    /// the [`PickList`].
+    ///
+    /// Scroll deltas are multiplied by the sensitivity and accumulated; the
+    /// selection only moves once a whole line has been accumulated. This
+    /// smooths out high-resolution wheels and trackpads. The accumulator is
+    /// reset whenever the scroll direction changes.
+    ///
+    /// By default, every wheel event moves the selection by one option.
+    pub fn scroll_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.scroll_sensitivity = Some(sensitivity);
+        self
+    }
+
+    /// Sets whether the selection keeps changing for a short while after a
+    /// quick scroll over the [`PickList`] stops, slowing down until it
+    /// settles.
+    ///
+    /// Scrolling again, in either direction, takes over from the momentum.
+    /// This is `false` by default.
+    pub fn scroll_momentum(mut self, scroll_momentum: bool) -> Self {
+        self.scroll_momentum = scroll_momentum;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`PickList`] is opened.
+    pub fn on_open(mut self, on_open: Message) -> Self {
+        self.on_open = Some(on_open);
+        self
+    }
+
+    /// Sets the message that will be produced when an option is selected,
+    /// given the [`SelectSource`] of the selection.
+    ///
+    /// It replaces the `on_select` message, which is still required by
+    /// [`PickList::new`].
+    pub fn on_select_with(
+        mut self,
+        on_select_with: impl Fn(T, SelectSource) -> Message + 'a,
+    ) -> Self {
+        self.on_select_with = Some(Box::new(on_select_with));
+        self
+    }
+
+    /// Sets what happens when the selected option is picked again in the
+    /// open [`Menu`].
+    ///
+    /// The selection message is produced again by default.
+    pub fn on_reselect(
+        mut self,
+        on_reselect: ReselectBehavior<Message>,
+    ) -> Self {
+        self.on_reselect = on_reselect;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`PickList`] is opened,
+    /// given the bounds of the field.
+    ///
+    /// The [`Rectangle`] is the one of the field the [`Menu`] is anchored to,
+    /// not the final bounds of the [`Menu`] overlay. It can be combined with
+    /// [`on_open`](Self::on_open).
+    pub fn on_open_with(
+        mut self,
+        on_open_with: impl Fn(Rectangle) -> Message + 'a,
+    ) -> Self {
+        self.on_open_with = Some(Box::new(on_open_with));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`PickList`] is closed.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets the message that will be produced once the [`Menu`] is placed,
+    /// given whether it opened upward, above the field.
+    ///
+    /// The message is produced once per open.
+    pub fn on_flip(mut self, on_flip: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_flip = Some(Box::new(on_flip));
+        self
+    }
+
+    /// Sets the message that will be produced when Escape is pressed while
+    /// the [`PickList`] is focused and closed, allowing the selection to be
+    /// cleared.
+    ///
+    /// While the [`Menu`] is open, Escape closes it instead and this message
+    /// is not produced.
+    pub fn on_clear(mut self, on_clear: Message) -> Self {
+        self.on_clear = Some(on_clear);
+        self
+    }
+
+    /// Sets the message that will be produced with the vertical scroll
+    /// offset of the open [`Menu`], in pixels.
+    ///
+    /// The message is only produced when the offset actually changes.
+    pub fn on_menu_scroll(
+        mut self,
+        on_menu_scroll: impl Fn(f32) -> Message + 'a,
+    ) -> Self {
+        self.on_menu_scroll = Some(Box::new(on_menu_scroll));
+        self
+    }
+
+    /// Sets the message that will be produced when the open [`Menu`] is
+    /// scrolled back to its first options, e.g. to refresh a list of recent
+    /// items.
+    ///
+    /// It is produced once each time the top is reached, but neither while
+    /// the [`Menu`] stays there nor when it opens there.
+    pub fn on_scroll_start(mut self, on_scroll_start: Message) -> Self {
+        self.on_scroll_start = Some(on_scroll_start);
+        self
+    }
+
+    /// Sets the message that will be produced when the cursor moves over a
+    /// disabled option of the open [`Menu`].
+    ///
+    /// Disabled options are hovered like any other, but they cannot be
+    /// selected. The message is produced once each time the cursor enters a
+    /// disabled option, e.g. to explain why it is unavailable.
+    pub fn on_disabled_hover(
+        mut self,
+        on_disabled_hover: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        self.on_disabled_hover = Some(Box::new(on_disabled_hover));
+        self
+    }
+
+    /// Sets the message that will be produced when an option of the open
+    /// [`Menu`] is double clicked, e.g. to confirm it.
+    ///
+    /// When set, a single click only highlights an option, like hovering
+    /// it does. A double click selects it as usual, closing the [`Menu`],
+    /// and then produces this message. Keyboard and touch selections are
+    /// unaffected.
+    pub fn on_option_double_click(
+        mut self,
+        on_option_double_click: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        self.on_option_double_click = Some(Box::new(on_option_double_click));
+        self
+    }
+
+    /// Sets the message that will be produced with the position of the
+    /// cursor as it moves over the options of the open [`Menu`], relative to
+    /// their top-left corner.
+    ///
+    /// Positions are throttled to one every [`menu::CURSOR_REPORT_INTERVAL`],
+    /// which suits effects following the cursor, e.g. a custom highlight.
+    pub fn on_menu_cursor(
+        mut self,
+        on_menu_cursor: impl Fn(Point) -> Message + 'a,
+    ) -> Self {
+        self.on_menu_cursor = Some(Box::new(on_menu_cursor));
+        self
+    }
+
+    /// Sets the message that will be produced when the keyboard modifiers
+    /// change while the [`PickList`] is hovered or focused.
+    ///
+    /// This is useful to show hints for modifier-dependent actions.
+    pub fn on_modifiers(
+        mut self,
+        on_modifiers: impl Fn(keyboard::Modifiers) -> Message + 'a,
+    ) -> Self {
+        self.on_modifiers = Some(Box::new(on_modifiers));
+        self
+    }
+
+    /// Sets the function that maps the keys pressed while the [`Menu`] is
+    /// open to messages.
+    ///
+    /// The function sees every key before the built-in handling of the
+    /// [`PickList`]; returning a message claims the key, while returning
+    /// `None` lets the [`PickList`] handle it as usual.
+    pub fn on_menu_key(
+        mut self,
+        on_menu_key: impl Fn(keyboard::Key) -> Option<Message> + 'a,
+    ) -> Self {
+        self.on_menu_key = Some(Box::new(on_menu_key));
+        self
+    }
+
+    /// Sets the message that will be produced when navigating with the
+    /// wheel or the arrow keys would move past the first or last enabled
+    /// option.
+    ///
+    /// The selection is left untouched in that case.
+    pub fn on_nav_edge(
+        mut self,
+        on_nav_edge: impl Fn(Edge) -> Message + 'a,
+    ) -> Self {
+        self.on_nav_edge = Some(Box::new(on_nav_edge));
+        self
+    }
+
+    /// Makes keyboard navigation in the open [`Menu`] take priority over the
+    /// mouse for the given duration.
+    ///
+    /// While the priority lasts, moving the mouse does not change the
+    /// highlighted option, so that both input methods do not fight over it.
+    /// It is disabled by default.
+    pub fn keyboard_priority(mut self, priority: Duration) -> Self {
+        self.keyboard_priority = Some(priority);
+        self
+    }
+
+    /// Sets the message that will be produced with the new amount of
+    /// options whenever it changes between layouts.
+    ///
+    /// The initial amount of options is not reported. Since layout cannot
+    /// produce messages, the change is reported on the next event.
+    pub fn on_options_changed(
+        mut self,
+        on_options_changed: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_options_changed = Some(Box::new(on_options_changed));
+        self
+    }
+
+    /// Sets the message that will be produced when a [`PickList`] with a
+    /// [`Length::Shrink`] width is laid out narrower than its widest option,
+    /// e.g. because of the limits of its parent.
+    ///
+    /// Only the measured options count, see [`width_sample`], and the space
+    /// of the displayed handle is taken into account. The message is produced
+    /// once each time the [`PickList`] becomes too narrow. Since layout cannot
+    /// produce messages, it is produced when the window redraws right after
+    /// the layout.
+    ///
+    /// [`width_sample`]: Self::width_sample
+    pub fn on_clip(mut self, on_clip: Message) -> Self {
+        self.on_clip = Some(on_clip);
+        self
+    }
+
+    /// Sets the style of the [`PickList`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the [`Menu`].
+    #[must_use]
+    pub fn menu_style(
+        mut self,
+        style: impl Fn(&Theme) -> menu::Style + 'a,
+    ) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`PickList`].
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the [`Menu`].
+    #[must_use]
+    pub fn menu_class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    /// Finds the enabled option the given number of enabled options away
+    /// from the selected one, moving forward or backward through the options.
+    ///
+    /// If no option is selected, or the selected value is not among the
+    /// options, counting starts before the first enabled option in that
+    /// direction. Counting stops early at the last enabled option.
+    fn next_enabled(&self, forward: bool, count: usize) -> Option<&T> {
+        let from = self.selected_index();
+
+        let index = std::iter::successors(
+            self.next_enabled_index(from, forward, 1),
+            |index| self.next_enabled_index(Some(*index), forward, 1),
+        )
+        .take(count)
+        .last()?;
+
+        self.options.borrow().get(index)
+    }
+
+    /// Returns whether the option at the given index is disabled.
+    fn is_disabled(&self, index: usize) -> bool {
+        self.disabled.as_ref().is_some_and(|disabled| {
+            disabled(self.options.borrow())
+                .get(index)
+                .copied()
+                .unwrap_or(false)
+        })
+    }
+
+    /// Finds the index of the enabled option next to the given one, moving
+    /// forward or backward through the options by the given step.
+    ///
+    /// Without a starting index, the first enabled option in that direction
+    /// is returned.
+    fn next_enabled_index(
+        &self,
+        from: Option<usize>,
+        forward: bool,
+        step: usize,
+    ) -> Option<usize> {
+        let options = self.options.borrow();
+        let disabled = self
+            .disabled
+            .as_ref()
+            .map(|f| f(options))
+            .unwrap_or_else(|| vec![false; options.len()]);
+        let is_enabled =
+            |i: &usize| !disabled.get(*i).copied().unwrap_or(false);
+
+        match from {
+            Some(position) if forward => (position + step..options.len())
+                .step_by(step)
+                .find(is_enabled),
+            Some(position) => {
+                (0..=position).rev().step_by(step).skip(1).find(is_enabled)
+            }
+            None if forward => (0..options.len()).find(is_enabled),
+            None => (0..options.len()).rev().find(is_enabled),
+        }
+    }
+
+    /// Finds the index of the enabled option matching the characters typed
+    /// while the [`Menu`] is open.
+    ///
+    /// Typing the same character repeatedly cycles through the options
+    /// starting with it, from the one after the hovered option. Any other
+    /// input is a prefix, matched from the hovered option onwards.
+    fn type_ahead_index(
+        &self,
+        typed: &str,
+        hovered: Option<usize>,
+    ) -> Option<usize> {
+        let options = self.options.borrow();
+        let len = options.len();
+
+        // Options are matched in the order they are displayed in
+        let displayed = |index: usize| {
+            if self.reverse {
+                len - 1 - index
+            } else {
+                index
+            }
+        };
+        let hovered = hovered.filter(|index| *index < len).map(displayed);
+
+        let mut characters = typed.chars();
+        let first = characters.next()?;
+        let is_repeated = characters.all(|character| character == first);
+
+        let (prefix, start) = if is_repeated {
+            (&typed[..first.len_utf8()], hovered.map_or(0, |i| i + 1))
+        } else {
+            (typed, hovered.unwrap_or(0))
+        };
+
+        let disabled = self
+            .disabled
+            .as_ref()
+            .map(|f| f(options))
+            .unwrap_or_else(|| vec![false; len]);
+
+        (0..len)
+            .map(|offset| displayed((start + offset) % len))
+            .find(|i| {
+                !disabled.get(*i).copied().unwrap_or(false)
+                    && options[*i]
+                        .to_string()
+                        .to_lowercase()
+                        .starts_with(prefix)
+            })
+    }
+
+    /// Builds the [`Menu`] of the [`PickList`] for a field of the given
+    /// width.
+    fn menu<'b>(
+        &'b self,
+        state: &'b mut State<Renderer::Paragraph>,
+        target_width: f32,
+        renderer: &Renderer,
+    ) -> Menu<'b, 'a, T, Message, Theme, Renderer> {
+        let font = self.text_font(renderer);
+        let selected_index = self.selected_index();
+        let options = self.options.borrow();
+        let disabled = self.disabled.as_ref().map(|f| f(options));
+
+        let on_select = &self.on_select;
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            options,
+            &mut state.hovered_option,
+            on_select,
+            disabled,
+            None,
+            &self.menu_class,
+        )
+        .on_selected_with(|option, source| {
+            state.is_open = false;
+
+            self.select_in_menu(option, source)
+        })
+        .description(self.option_description.as_deref())
+        .shortcut(self.option_shortcut.as_deref())
+        .rich_label(self.rich_label.as_deref())
+        .indicator(self.option_indicator.as_deref())
+        .tooltip(self.tooltip.as_deref())
+        .option_font(self.option_font.as_deref())
+        .dividers(self.labeled_dividers.as_ref().map(|f| f(options)))
+        .row_busy(self.row_busy.as_deref())
+        .disabled_style(self.disabled_style.as_deref())
+        .on_scroll(self.on_menu_scroll.as_deref())
+        .on_scroll_start(self.on_scroll_start.clone())
+        .on_disabled_hover(self.on_disabled_hover.as_deref())
+        .on_option_double_click(self.on_option_double_click.as_deref())
+        .on_cursor(self.on_menu_cursor.as_deref())
+        .on_flip(self.on_flip.as_deref())
+        .header(self.menu_header.as_deref())
+        .sticky_header(self.sticky_header)
+        .close_button(self.menu_close_button)
+        .close_button_label(
+            self.menu_close_button_label
+                .as_deref()
+                .unwrap_or(menu::DEFAULT_CLOSE_BUTTON_LABEL),
+        )
+        .no_results_message(self.no_results_message.as_deref())
+        .pinned_option(if self.selected_first {
+            selected_index
+        } else {
+            None
+        })
+        .width(
+            self.menu_min_width
+                .map_or(target_width, |min| target_width.max(min)),
+        )
+        .alignment(self.menu_alignment)
+        .target_width(target_width)
+        .trap_scroll(self.trap_scroll)
+        .resizable(self.menu_resizable)
+        .snap_rows(self.snap_rows)
+        .overscroll(self.overscroll)
+        .padding(self.menu_padding.unwrap_or(self.padding))
+        .font(font)
+        .text_shaping(self.text_shaping)
+        .orientation(self.orientation)
+        .scrollbar(self.menu_scrollbar)
+        .columns(self.columns)
+        .reverse(self.reverse)
+        .scroll(self.menu_scroll)
+        .border_radius(self.corner_radius);
+
+        if self.menu_border_from_field {
+            let class = &self.class;
+
+            menu = menu.border(move |theme| {
+                Catalog::style(theme, class, Status::Opened).border
+            });
+        }
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        if let Some(line_height) = self.menu_line_height {
+            menu = menu.text_line_height(line_height);
+        }
+
+        if let Some(text_wrapping) = self.text_wrapping {
+            menu = menu.text_wrapping(text_wrapping);
+        }
+
+        menu
+    }
+
+    /// Finds the index of the enabled option nearest to the given one,
+    /// clamped to the options, preferring the following ones.
+    fn nearest_enabled_index(&self, index: usize) -> Option<usize> {
+        let options = self.options.borrow();
+        let index = index.min(options.len().checked_sub(1)?);
+        let disabled = self
+            .disabled
+            .as_ref()
+            .map(|f| f(options))
+            .unwrap_or_else(|| vec![false; options.len()]);
+        let is_enabled =
+            |i: &usize| !disabled.get(*i).copied().unwrap_or(false);
+
+        (index..options.len())
+            .find(is_enabled)
+            .or_else(|| (0..index).rev().find(is_enabled))
+    }
+
+    /// Produces the message of selecting the given option.
+    fn select(&self, option: T, source: SelectSource) -> Message {
+        match &self.on_select_with {
+            Some(on_select_with) => on_select_with(option, source),
+            None => (self.on_select)(option),
+        }
+    }
+
+    /// Produces the message of selecting the given option in the [`Menu`],
+    /// following the [`ReselectBehavior`] when it is already selected.
+    fn select_in_menu(
+        &self,
+        option: T,
+        source: SelectSource,
+    ) -> Option<Message> {
+        if self.selected_option() != Some(&option) {
+            return Some(self.select(option, source));
+        }
+
+        match &self.on_reselect {
+            ReselectBehavior::Reemit => Some(self.select(option, source)),
+            ReselectBehavior::Ignore => None,
+            ReselectBehavior::Clear(message) => Some(message.clone()),
+        }
+    }
+
+    /// Selects the enabled option the given number of enabled options away in
+    /// the given direction, or notifies that the navigation has reached an
+    /// [`Edge`] of the options.
+    ///
+    /// Returns whether an option was selected.
+    fn navigate(
+        &self,
+        forward: bool,
+        count: usize,
+        source: SelectSource,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        // Navigation follows the order of the options in the open menu
+        let forward = forward != self.reverse;
+
+        if let Some(next_option) = self.next_enabled(forward, count) {
+            shell.publish(self.select(next_option.clone(), source));
+
+            return true;
+        }
+
+        if let Some(on_nav_edge) = &self.on_nav_edge {
+            shell.publish(on_nav_edge(if forward {
+                Edge::Last
+            } else {
+                Edge::First
+            }));
+        }
+
+        false
+    }
+
+    /// Returns the font of the text of the [`PickList`] and its [`Menu`].
+    ///
+    /// Text is measured during layout with this same font, so that it is
+    /// never clipped when drawn.
+    fn text_font(&self, renderer: &Renderer) -> Renderer::Font {
+        self.font.unwrap_or_else(|| renderer.default_font())
+    }
+
+    /// Returns the font of the label of the given option, both in the field
+    /// and in the [`Menu`], or the text font without an option.
+    fn label_font(
+        &self,
+        option: Option<&T>,
+        renderer: &Renderer,
+    ) -> Renderer::Font {
+        option
+            .and_then(|option| self.option_font.as_ref()?(option))
+            .unwrap_or_else(|| self.text_font(renderer))
+    }
+
+    /// Get the width taken by the placeholder icon, including its spacing
+    fn placeholder_icon_width(&self, renderer: &Renderer) -> f32 {
+        self.placeholder_icon.as_ref().map_or(0.0, |icon| {
+            icon.size.unwrap_or_else(|| renderer.default_size()).0
+                + PLACEHOLDER_ICON_SPACING
+        })
+    }
+
+    /// Returns the icon of the [`Handle`] displayed with the given [`Status`],
+    /// if any.
+    fn handle_icon(
+        &self,
+        status: Status,
+        renderer: &Renderer,
+    ) -> Option<Icon<Renderer::Font>> {
+        let is_open = status == Status::Opened;
+
+        if let Some(handle_with) = &self.handle_with {
+            return handle_with(status, is_open);
+        }
+
+        match &self.handle {
+            Handle::Arrow { size } => {
+                // The icon font only has a down arrow, so flipping arrows are
+                // both drawn with the default font to match
+                let (font, code_point) = if !self.flip_handle {
+                    (Renderer::ICON_FONT, Renderer::ARROW_DOWN_ICON)
+                } else if is_open {
+                    (renderer.default_font(), ARROW_UP_ICON)
+                } else {
+                    (renderer.default_font(), ARROW_DOWN_ICON)
+                };
+
+                Some(Icon {
+                    font,
+                    code_point,
+                    size: *size,
+                    line_height: text::LineHeight::default(),
+                    shaping: text::Shaping::Basic,
+                })
+            }
+            Handle::Static(icon) => Some(icon.clone()),
+            Handle::Dynamic { open, closed } => Some(if is_open {
+                open.clone()
+            } else {
+                closed.clone()
+            }),
+            Handle::None => None,
+        }
+    }
+
+    /// Get the width taken by the handle displayed with the given [`Status`],
+    /// including its spacing
+    fn handle_width(&self, status: Status, renderer: &Renderer) -> f32 {
+        self.handle_icon(status, renderer).map_or(0.0, |icon| {
+            icon.size.unwrap_or_else(|| renderer.default_size()).0
+                + self.handle_spacing
+        })
+    }
+
+    /// Returns whether the label of the closed [`PickList`] is truncated to
+    /// fit in the given bounds, as measured in its [`State`], next to the
+    /// handle displayed with the given [`Status`].
+    fn is_label_truncated(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        status: Status,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) -> bool {
+        if !self.truncate || self.icon_only {
+            return false;
+        }
+
+        let selected = self
+            .selected_index()
+            .and_then(|index| state.options.get(index));
+
+        let (label_width, suffix, badge_width) = match selected {
+            Some(paragraphs) => (
+                paragraphs.label.min_width(),
+                paragraphs.suffix.min_width(),
+                if self.selected_badge {
+                    BADGE_PADDING * 2.0
+                } else {
+                    0.0
+                },
+            ),
+            None if self.placeholder.is_some() => (
+                state.placeholder.min_width()
+                    + self.placeholder_icon_width(renderer),
+                state.placeholder_suffix.min_width(),
+                0.0,
+            ),
+            None => return false,
+        };
+
+        let handle_width = self.handle_width(status, renderer);
+
+        label_width
+            > bounds.width
+                - self.padding.horizontal()
+                - handle_width
+                - suffix_width(suffix)
+                - badge_width
+    }
+
+    /// Returns the selected option, either given by value or by index.
+    fn selected_option(&self) -> Option<&T> {
+        self.selected.as_ref().map(Borrow::borrow).or_else(|| {
+            self.selected_index
+                .and_then(|index| self.options.borrow().get(index))
+        })
+    }
+
+    /// Returns the index of the selected option in the list of options.
+    fn selected_index(&self) -> Option<usize> {
+        let options = self.options.borrow();
+
+        match &self.selected {
+            Some(selected) => {
+                let selected = selected.borrow();
+
+                options.iter().position(|option| option == selected)
+            }
+            None => self.selected_index.filter(|index| *index < options.len()),
+        }
+    }
+
+    /// Returns the index of the option to highlight when the [`Menu`] opens,
+    /// given the one that was hovered when it last closed.
+    fn hovered_option_on_open(
+        &self,
+        last_hovered: Option<usize>,
+    ) -> Option<usize> {
+        let len = self.options.borrow().len();
+
+        last_hovered
+            .filter(|_| self.restore_hover_on_open && len > 0)
+            .map(|index| index.min(len - 1))
+            .or_else(|| self.selected_index())
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for PickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'a,
+    L: Borrow<[T]>,
+    V: Borrow<T>,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        // The options may have changed, so a closed menu is measured again
+        // the next time its height is needed
+        if !state.is_open {
+            state.menu.forget_content_height();
+        }
+
+        let font = self.text_font(renderer);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let options = self.options.borrow();
+
+        // Layout cannot produce messages, so the change is published on the
+        // next event
+        if state.is_initialized && state.options.len() != options.len() {
+            state.pending_options_len = Some(options.len());
+        }
+
+        state.options.resize_with(options.len(), Default::default);
+
+        if !state.is_initialized {
+            state.is_initialized = true;
+
+            if self.initially_open {
+                state.is_open = true;
+                state.is_pending_open = true;
+                state.menu.forget_placement();
+                state.menu.hide_tooltip();
+                state.hovered_option =
+                    self.hovered_option_on_open(state.hovered_option);
+            }
+        }
+
+        let option_text = Text {
+            content: "",
+            bounds: Size::new(
+                f32::INFINITY,
+                self.text_line_height.to_absolute(text_size).into(),
+            ),
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::default(),
+        };
+
+        let description_size = menu::description_text_size(text_size);
+
+        // Only a sample of the options is measured, along with the selected
+        // one, whose label is shown in the field
+        let selected_index = self.selected_index();
+        let is_measured = |index: usize| {
+            index < self.width_sample.unwrap_or(usize::MAX)
+                || Some(index) == selected_index
+        };
+
+        for (index, (option, paragraphs)) in
+            options.iter().zip(state.options.iter_mut()).enumerate()
+        {
+            if !is_measured(index) {
+                continue;
+            }
+
+            let label = option.to_string();
+
+            // Rows are measured in the font they are drawn with
+            let label_font = self.label_font(Some(option), renderer);
+
+            paragraphs.label.update(Text {
+                content: &label,
+                font: label_font,
+                ..option_text
+            });
+
+            let description = self
+                .option_description
+                .as_ref()
+                .and_then(|description| description(option))
+                .unwrap_or_default();
+
+            paragraphs.description.update(Text {
+                content: &description,
+                size: description_size,
+                bounds: Size::new(
+                    f32::INFINITY,
+                    self.text_line_height.to_absolute(description_size).into(),
+                ),
+                ..option_text
+            });
+
+            let suffix = self
+                .suffix
+                .as_ref()
+                .and_then(|suffix| suffix(Some(option)))
+                .unwrap_or_default();
+
+            paragraphs.suffix.update(Text {
+                content: &suffix,
+                ..option_text
+            });
+
+            let shortcut = self
+                .option_shortcut
+                .as_ref()
+                .and_then(|shortcut| shortcut(option))
+                .unwrap_or_default();
+
+            paragraphs.shortcut.update(Text {
+                content: &shortcut,
+                ..option_text
+            });
+        }
+
+        if let Some(placeholder) = &self.placeholder {
+            state.placeholder.update(Text {
+                content: placeholder,
+                ..option_text
+            });
+
+            let suffix = self
+                .suffix
+                .as_ref()
+                .and_then(|suffix| suffix(None))
+                .unwrap_or_default();
+
+            state.placeholder_suffix.update(Text {
+                content: &suffix,
+                ..option_text
+            });
+        }
+
+        let max_width = match self.width {
+            _ if self.icon_only => 0.0,
+            Length::Shrink => {
+                // Rows are as wide as their widest line of content
+                let labels_width = state
+                    .options
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| is_measured(*index))
+                    .fold(0.0, |width: f32, (_, paragraphs)| {
+                        width.max(paragraphs.width())
+                    });
+
+                // Menu rows reserve space for their status indicator
+                let labels_width = if self.option_indicator.is_some()
+                    || self.row_busy.is_some()
+                {
+                    labels_width + menu::INDICATOR_WIDTH
+                } else {
+                    labels_width
+                };
+
+                labels_width.max(
+                    self.placeholder
+                        .as_ref()
+                        .map(|_| {
+                            state.placeholder.min_width()
+                                + self.placeholder_icon_width(renderer)
+                                + suffix_width(
+                                    state.placeholder_suffix.min_width(),
+                                )
+                        })
+                        .unwrap_or(0.0),
+                )
+            }
+            _ => 0.0,
+        };
+
+        let badge_width = if self.selected_badge && !self.icon_only {
+            BADGE_PADDING * 2.0
+        } else {
+            0.0
+        };
+
+        let size = {
+            let intrinsic = Size::new(
+                max_width
+                    + badge_width
+                    + text_size.0
+                    + self.handle_spacing
+                    + self.padding.left,
+                f32::from(self.text_line_height.to_absolute(text_size)),
+            );
+
+            limits
+                .width(self.width)
+                .shrink(self.padding)
                 .resolve(self.width, Length::Shrink, intrinsic)
                 .expand(self.padding)
         };
 
+        if self.width == Length::Shrink && !self.icon_only {
+            let widest = state
+                .options
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| is_measured(*index))
+                .fold(0.0, |width: f32, (_, paragraphs)| {
+                    width.max(
+                        paragraphs.label.min_width()
+                            + suffix_width(paragraphs.suffix.min_width()),
+                    )
+                });
+
+            let status = if state.is_open {
+                Status::Opened
+            } else {
+                Status::Active
+            };
+
+            let is_clipped = widest
+                > size.width
+                    - self.padding.horizontal()
+                    - badge_width
+                    - self.handle_width(status, renderer);
+
+            // Layout cannot produce messages, so the clip is published on the
+            // redraw event that follows it
+            if is_clipped && !state.is_clipped {
+                state.is_pending_clip = true;
+            }
+
+            state.is_clipped = is_clipped;
+        }
+
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(name) = &self.name {
+            operation.custom(&mut Name(name.clone()), None);
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        // An open menu is measured whenever it is laid out, while a closed
+        // one is only measured when its height is asked for
+        let content_height = state.menu.content_height().or_else(|| {
+            let mut measure = MeasureContentHeight(false);
+            operation.custom(&mut measure, self.id.as_ref());
+
+            measure.0.then(|| {
+                self.menu(state, layout.bounds().width, renderer)
+                    .content_height(renderer)
+            })
+        });
+
+        if let Some(height) = content_height {
+            operation.custom(&mut ContentHeight(height), self.id.as_ref());
+        }
+        operation.focusable(state, self.id.as_ref());
+
+        // The menu can only be operated on while it is open
+        if state.is_open {
+            operation.custom(&mut state.menu, self.id.as_ref());
+
+            if let Some(index) = state.hovered_option {
+                operation.custom(&mut HoveredOption(index), self.id.as_ref());
+            }
+
+            let mut hover = Hover(None);
+            operation.custom(&mut hover, self.id.as_ref());
+
+            if let Some(index) =
+                hover.0.and_then(|index| self.nearest_enabled_index(index))
+            {
+                state.hovered_option = Some(index);
+            }
+
+            let mut open = Open(true);
+            operation.custom(&mut open, self.id.as_ref());
+
+            if !open.0 {
+                state.is_open = false;
+            }
+        }
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if std::mem::take(&mut state.is_pending_open) {
+            if let Some(on_open) = &self.on_open {
+                shell.publish(on_open.clone());
+            }
+
+            if let Some(on_open_with) = &self.on_open_with {
+                shell.publish(on_open_with(layout.bounds()));
+            }
+        }
+
+        if let Some(len) = state.pending_options_len.take() {
+            if let Some(on_options_changed) = &self.on_options_changed {
+                shell.publish(on_options_changed(len));
+            }
+        }
+
+        if std::mem::take(&mut state.is_pending_clip) {
+            if let Some(on_clip) = &self.on_clip {
+                shell.publish(on_clip.clone());
+            }
+        }
+
+        // The focus ring is only shown while the keyboard is in use
+        match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed { .. })
+                if state.is_focused =>
+            {
+                state.is_focus_visible = true;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focus_visible = false;
+            }
+            _ => {}
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = &event
+        {
+            if let Some(message) = self
+                .on_menu_key
+                .as_ref()
+                .filter(|_| state.is_open)
+                .and_then(|on_menu_key| on_menu_key(key.clone()))
+            {
+                shell.publish(message);
+
+                return event::Status::Captured;
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -467,7 +2088,31 @@ where
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 if state.is_open {
-                    if let Some(hovered) = state.hovered_option {
+                    // A tapped option is still showing its pressed feedback
+                    // and will be selected shortly
+                    if state.menu.is_pressed() {
+                        return event::Status::Captured;
+                    }
+
+                    // A press on the field itself toggles the menu closed,
+                    // regardless of the hovered option
+                    if cursor.is_over(layout.bounds()) {
+                        state.is_open = false;
+                        state.is_focused = true;
+
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close.clone());
+                        }
+
+                        return event::Status::Captured;
+                    }
+
+                    // The cursor is unavailable when pressing over the menu,
+                    // where a disabled option may be hovered
+                    if let Some(hovered) = state
+                        .hovered_option
+                        .filter(|_| cursor.position().is_none())
+                    {
                         let options = self.options.borrow();
                         if let Some(disabled_fn) = &self.disabled {
                             let disabled = disabled_fn(options);
@@ -482,33 +2127,44 @@ where
                     // or on an enabled option, either way we close the overlay.
                     state.is_open = false;
 
+                    // The cursor is unavailable when pressing over the menu
+                    state.is_focused = cursor.position().is_none();
+
                     if let Some(on_close) = &self.on_close {
                         shell.publish(on_close.clone());
                     }
 
                     event::Status::Captured
                 } else if cursor.is_over(layout.bounds()) {
-                    let selected = self.selected.as_ref().map(Borrow::borrow);
-
                     state.is_open = true;
-                    state.hovered_option = self
-                        .options
-                        .borrow()
-                        .iter()
-                        .position(|option| Some(option) == selected);
+                    state.is_focused = true;
+                    state.is_opened_on_hover = false;
+                    state.hovered_at = None;
+                    state.menu.forget_placement();
+                    state.menu.hide_tooltip();
+                    state.menu.set_keyboard_navigation(false);
+                    state.hovered_option =
+                        self.hovered_option_on_open(state.hovered_option);
 
                     if let Some(on_open) = &self.on_open {
                         shell.publish(on_open.clone());
                     }
 
+                    if let Some(on_open_with) = &self.on_open_with {
+                        shell.publish(on_open_with(layout.bounds()));
+                    }
+
                     event::Status::Captured
                 } else {
+                    // The cursor is unavailable when pressing over the menu
+                    if cursor.position().is_some() {
+                        state.is_focused = false;
+                    }
+
                     event::Status::Ignored
                 }
             }
-            Event::Mouse(mouse::Event::WheelScrolled {
-                delta: mouse::ScrollDelta::Lines { y, .. },
-            }) => {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 let state =
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
@@ -516,83 +2172,480 @@ where
                     && cursor.is_over(layout.bounds())
                     && !state.is_open
                 {
-                    fn find_next<'a, T: PartialEq>(
-                        selected: &'a T,
-                        mut options: impl Iterator<Item = &'a T>,
-                    ) -> Option<&'a T> {
-                        let _ = options.find(|&option| option == selected);
+                    let y = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => y,
+                        mouse::ScrollDelta::Pixels { y, .. } => {
+                            y / PIXELS_PER_LINE
+                        }
+                    };
+
+                    let steps = match self.scroll_sensitivity {
+                        Some(sensitivity) => {
+                            let y = y * sensitivity;
+
+                            if y.signum() != state.scroll_accumulation.signum()
+                            {
+                                state.scroll_accumulation = 0.0;
+                            }
+
+                            state.scroll_accumulation += y;
+
+                            let steps = state.scroll_accumulation.trunc();
+                            state.scroll_accumulation -= steps;
+
+                            steps
+                        }
+                        None if matches!(
+                            delta,
+                            mouse::ScrollDelta::Lines { .. }
+                        ) =>
+                        {
+                            y.signum()
+                        }
+                        None => 0.0,
+                    };
+
+                    // A scroll with no effect is left to the parents
+                    if steps == 0.0 && !self.scroll_momentum {
+                        return event::Status::Ignored;
+                    }
+
+                    // Every accumulated line moves the selection by one option
+                    let count = steps.abs() as usize;
+
+                    if steps < 0.0 && self.scroll_down {
+                        self.navigate(true, count, SelectSource::Scroll, shell);
+                    } else if steps > 0.0 && self.scroll_up {
+                        self.navigate(
+                            false,
+                            count,
+                            SelectSource::Scroll,
+                            shell,
+                        );
+                    }
+
+                    if self.scroll_momentum {
+                        let now = Instant::now();
+                        let y = y * self.scroll_sensitivity.unwrap_or(1.0);
+
+                        // The velocity is estimated from consecutive scrolls
+                        let velocity = state
+                            .momentum
+                            .filter(|momentum| momentum.ticked_at.is_none())
+                            .map_or(0.0, |momentum| {
+                                let elapsed = (now - momentum.scrolled_at)
+                                    .as_secs_f32()
+                                    .max(f32::EPSILON);
+                                let velocity = (y / elapsed).clamp(
+                                    -MOMENTUM_MAX_VELOCITY,
+                                    MOMENTUM_MAX_VELOCITY,
+                                );
+
+                                if velocity.signum()
+                                    == momentum.velocity.signum()
+                                {
+                                    (velocity + momentum.velocity) / 2.0
+                                } else {
+                                    velocity
+                                }
+                            });
+
+                        state.momentum = Some(Momentum {
+                            velocity,
+                            scrolled_at: now,
+                            ticked_at: None,
+                            accumulation: 0.0,
+                        });
+
+                        shell.request_redraw(window::RedrawRequest::At(
+                            now + MOMENTUM_IDLE,
+                        ));
+                    }
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if (matches!(
+                key,
+                keyboard::key::Named::ArrowUp
+                    | keyboard::key::Named::ArrowDown
+                    | keyboard::key::Named::Enter
+            ) || self.columns > 1
+                && matches!(
+                    key,
+                    keyboard::key::Named::ArrowLeft
+                        | keyboard::key::Named::ArrowRight
+                ))
+                && tree
+                    .state
+                    .downcast_ref::<State<Renderer::Paragraph>>()
+                    .is_open =>
+            {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if key == keyboard::key::Named::Enter {
+                    let option = state
+                        .hovered_option
+                        .filter(|index| !self.is_disabled(*index))
+                        .and_then(|index| self.options.borrow().get(index));
+
+                    if let Some(option) = option {
+                        state.is_open = false;
+
+                        if let Some(message) = self.select_in_menu(
+                            option.clone(),
+                            SelectSource::Keyboard,
+                        ) {
+                            shell.publish(message);
+                        }
+                    }
+                } else {
+                    // Arrows follow the displayed order of the options
+                    let forward = matches!(
+                        key,
+                        keyboard::key::Named::ArrowDown
+                            | keyboard::key::Named::ArrowRight
+                    ) != self.reverse;
+
+                    // Vertical arrows move across rows of the grid
+                    let step = if matches!(
+                        key,
+                        keyboard::key::Named::ArrowUp
+                            | keyboard::key::Named::ArrowDown
+                    ) {
+                        self.columns
+                    } else {
+                        1
+                    };
+
+                    if let Some(index) = self.next_enabled_index(
+                        state.hovered_option,
+                        forward,
+                        step,
+                    ) {
+                        state.hovered_option = Some(index);
+                        state.menu.set_keyboard_navigation(true);
+                        state.menu.scroll_into_view(index);
+
+                        if let Some(priority) = self.keyboard_priority {
+                            state.menu.lock_hover(Instant::now() + priority);
+                        }
+                    } else if let Some(on_nav_edge) = &self.on_nav_edge {
+                        shell.publish(on_nav_edge(if forward {
+                            Edge::Last
+                        } else {
+                            Edge::First
+                        }));
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if self.arrows_change_closed
+                && matches!(
+                    key,
+                    keyboard::key::Named::ArrowUp
+                        | keyboard::key::Named::ArrowDown
+                ) =>
+            {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if state.is_focused && !state.is_open {
+                    self.navigate(
+                        key == keyboard::key::Named::ArrowDown,
+                        1,
+                        SelectSource::Keyboard,
+                        shell,
+                    );
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(character),
+                modifiers,
+                ..
+            }) if !modifiers.command()
+                && tree
+                    .state
+                    .downcast_ref::<State<Renderer::Paragraph>>()
+                    .is_open =>
+            {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+                let now = Instant::now();
+
+                // A pause starts a new search
+                if !state.typed_at.is_some_and(|typed_at| {
+                    now <= typed_at + TYPE_AHEAD_TIMEOUT
+                }) {
+                    state.typed.clear();
+                }
+
+                state.typed.push_str(&character.to_lowercase());
+                state.typed_at = Some(now);
+
+                if let Some(index) =
+                    self.type_ahead_index(&state.typed, state.hovered_option)
+                {
+                    state.hovered_option = Some(index);
+                    state.menu.set_keyboard_navigation(true);
+                    state.menu.scroll_into_view(index);
+
+                    if let Some(priority) = self.keyboard_priority {
+                        state.menu.lock_hover(now + priority);
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Tab),
+                ..
+            }) if tree
+                .state
+                .downcast_ref::<State<Renderer::Paragraph>>()
+                .is_open =>
+            {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                state.is_open = false;
+
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close.clone());
+                }
+
+                // The PickList stays focused until a focus operation moves
+                // the focus on to the next widget
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if state.is_open {
+                    state.is_open = false;
+
+                    if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+
+                    event::Status::Captured
+                } else if state.is_focused {
+                    if let Some(on_clear) = &self.on_clear {
+                        shell.publish(on_clear.clone());
+
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    }
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+                let is_hovered = cursor.is_over(layout.bounds());
+
+                if self.marquee_on_hover && is_hovered != state.is_hovered {
+                    state.marquee_started_at = None;
+                    state.marquee_offset = 0.0;
+
+                    if is_hovered {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+
+                let Some(delay) = self.open_on_hover else {
+                    state.is_hovered = is_hovered;
+
+                    return event::Status::Ignored;
+                };
+
+                if !state.is_open {
+                    if is_hovered && !state.is_hovered {
+                        let now = Instant::now();
+
+                        state.hovered_at = Some(now);
+                        shell.request_redraw(window::RedrawRequest::At(
+                            now + delay,
+                        ));
+                    } else if !is_hovered {
+                        state.hovered_at = None;
+                    }
+                } else if state.is_opened_on_hover {
+                    // The cursor is unavailable when it is over the menu
+                    if is_hovered || cursor.position().is_none() {
+                        state.left_at = None;
+                    } else if state.left_at.is_none() {
+                        let now = Instant::now();
+
+                        state.left_at = Some(now);
+                        shell.request_redraw(window::RedrawRequest::At(
+                            now + HOVER_CLOSE_GRACE,
+                        ));
+                    }
+                }
+
+                state.is_hovered = is_hovered;
+
+                event::Status::Ignored
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if let Some(mut momentum) = state.momentum {
+                    if state.is_open {
+                        state.momentum = None;
+                    } else if momentum.ticked_at.is_none()
+                        && now < momentum.scrolled_at + MOMENTUM_IDLE
+                    {
+                        shell.request_redraw(window::RedrawRequest::At(
+                            momentum.scrolled_at + MOMENTUM_IDLE,
+                        ));
+                    } else {
+                        let elapsed = momentum
+                            .ticked_at
+                            .map_or(0.0, |at| (now - at).as_secs_f32());
+
+                        momentum.velocity *= (-MOMENTUM_DECAY * elapsed).exp();
+                        momentum.accumulation += momentum.velocity * elapsed;
+                        momentum.ticked_at = Some(now);
+
+                        // Scrolling up selects the previous option
+                        let is_moved = if momentum.accumulation >= 1.0 {
+                            momentum.accumulation -= 1.0;
+
+                            self.scroll_up
+                                && self.navigate(
+                                    false,
+                                    1,
+                                    SelectSource::Scroll,
+                                    shell,
+                                )
+                        } else if momentum.accumulation <= -1.0 {
+                            momentum.accumulation += 1.0;
+
+                            self.scroll_down
+                                && self.navigate(
+                                    true,
+                                    1,
+                                    SelectSource::Scroll,
+                                    shell,
+                                )
+                        } else {
+                            true
+                        };
+
+                        if is_moved
+                            && momentum.velocity.abs() >= MOMENTUM_MIN_VELOCITY
+                        {
+                            state.momentum = Some(momentum);
+
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        } else {
+                            state.momentum = None;
+                        }
+                    }
+                }
+
+                if self.marquee_on_hover {
+                    if state.is_hovered
+                        && !state.is_open
+                        && self.is_label_truncated(
+                            state,
+                            if cursor.is_over(layout.bounds()) {
+                                Status::Hovered
+                            } else {
+                                Status::Active
+                            },
+                            layout.bounds(),
+                            renderer,
+                        )
+                    {
+                        let started_at =
+                            *state.marquee_started_at.get_or_insert(now);
+
+                        state.marquee_offset = (now - started_at)
+                            .saturating_sub(MARQUEE_DELAY)
+                            .as_secs_f32()
+                            * MARQUEE_SPEED;
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    } else {
+                        state.marquee_started_at = None;
+                        state.marquee_offset = 0.0;
+                    }
+                }
 
-                        options.next()
-                    }
+                let Some(delay) = self.open_on_hover else {
+                    return event::Status::Ignored;
+                };
 
-                    let options = self.options.borrow();
-                    let selected = self.selected.as_ref().map(Borrow::borrow);
-                    let disabled = self
-                        .disabled
-                        .as_ref()
-                        .map(|f| f(options))
-                        .unwrap_or_else(|| vec![false; options.len()]);
-
-                    let next_option = if y < 0.0 {
-                        if let Some(selected) = selected {
-                            let mut next = find_next(selected, options.iter());
-                            // Keep finding next until we hit a non-disabled
-                            // option or run out
-                            while let Some(option) = next {
-                                if let Some(pos) =
-                                    options.iter().position(|opt| opt == option)
-                                {
-                                    if !disabled[pos] {
-                                        break;
-                                    }
-                                }
-                                next = find_next(option, options.iter());
-                            }
-                            next
+                if !state.is_open {
+                    if let Some(hovered_at) = state.hovered_at {
+                        if now < hovered_at + delay {
+                            shell.request_redraw(window::RedrawRequest::At(
+                                hovered_at + delay,
+                            ));
                         } else {
-                            options
-                                .iter()
-                                .enumerate()
-                                .find(|(i, _)| !disabled[*i])
-                                .map(|(_, opt)| opt)
-                        }
-                    } else if y > 0.0 {
-                        if let Some(selected) = selected {
-                            let mut next =
-                                find_next(selected, options.iter().rev());
-                            // Keep finding next until we hit a non-disabled
-                            // option or run out
-                            while let Some(option) = next {
-                                if let Some(pos) =
-                                    options.iter().position(|opt| opt == option)
-                                {
-                                    if !disabled[pos] {
-                                        break;
-                                    }
-                                }
-                                next = find_next(option, options.iter().rev());
+                            state.is_open = true;
+                            state.is_opened_on_hover = true;
+                            state.hovered_at = None;
+                            state.left_at = None;
+                            state.menu.forget_placement();
+                            state.menu.hide_tooltip();
+                            state.menu.set_keyboard_navigation(false);
+                            state.hovered_option = self
+                                .hovered_option_on_open(state.hovered_option);
+
+                            if let Some(on_open) = &self.on_open {
+                                shell.publish(on_open.clone());
                             }
-                            next
-                        } else {
-                            options
-                                .iter()
-                                .enumerate()
-                                .rev()
-                                .find(|(i, _)| !disabled[*i])
-                                .map(|(_, opt)| opt)
-                        }
-                    } else {
-                        None
-                    };
 
-                    if let Some(next_option) = next_option {
-                        shell.publish((self.on_select)(next_option.clone()));
+                            if let Some(on_open_with) = &self.on_open_with {
+                                shell.publish(on_open_with(layout.bounds()));
+                            }
+                        }
                     }
+                } else if state.is_opened_on_hover {
+                    if let Some(left_at) = state.left_at {
+                        if now < left_at + HOVER_CLOSE_GRACE {
+                            shell.request_redraw(window::RedrawRequest::At(
+                                left_at + HOVER_CLOSE_GRACE,
+                            ));
+                        } else {
+                            state.is_open = false;
+                            state.left_at = None;
 
-                    event::Status::Captured
-                } else {
-                    event::Status::Ignored
+                            if let Some(on_close) = &self.on_close {
+                                shell.publish(on_close.clone());
+                            }
+                        }
+                    }
                 }
+
+                event::Status::Ignored
             }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 let state =
@@ -600,6 +2653,12 @@ where
 
                 state.keyboard_modifiers = modifiers;
 
+                if let Some(on_modifiers) = &self.on_modifiers {
+                    if state.is_focused || cursor.is_over(layout.bounds()) {
+                        shell.publish(on_modifiers(modifiers));
+                    }
+                }
+
                 event::Status::Ignored
             }
             _ => event::Status::Ignored,
@@ -634,8 +2693,8 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let font = self.font.unwrap_or_else(|| renderer.default_font());
-        let selected = self.selected.as_ref().map(Borrow::borrow);
+        let font = self.text_font(renderer);
+        let selected = self.selected_option();
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let options = self.options.borrow();
         let disabled_options = self
@@ -656,57 +2715,73 @@ where
             Status::Active
         };
 
-        let style = Catalog::style(theme, &self.class, status);
+        let mut style = Catalog::style(theme, &self.class, status);
+
+        if let Some(corner_radius) = self.corner_radius {
+            style.border.radius = corner_radius;
+        }
 
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border: style.border,
-                ..renderer::Quad::default()
+                shadow: style.shadow.unwrap_or_default(),
             },
-            style.background,
+            style
+                .hovered_background
+                .filter(|_| status == Status::Hovered)
+                .unwrap_or(style.background),
         );
 
-        let handle = match &self.handle {
-            Handle::Arrow { size } => Some((
-                Renderer::ICON_FONT,
-                Renderer::ARROW_DOWN_ICON,
-                *size,
-                text::LineHeight::default(),
-                text::Shaping::Basic,
-            )),
-            Handle::Static(Icon {
-                font,
-                code_point,
-                size,
-                line_height,
-                shaping,
-            }) => Some((*font, *code_point, *size, *line_height, *shaping)),
-            Handle::Dynamic { open, closed } => {
-                if state.is_open {
-                    Some((
-                        open.font,
-                        open.code_point,
-                        open.size,
-                        open.line_height,
-                        open.shaping,
-                    ))
-                } else {
-                    Some((
-                        closed.font,
-                        closed.code_point,
-                        closed.size,
-                        closed.line_height,
-                        closed.shaping,
-                    ))
-                }
-            }
-            Handle::None => None,
-        };
+        if let Some(focus_border) = style.focus_border.filter(|_| {
+            state.is_focused && state.is_focus_visible && !state.is_open
+        }) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: style.border.radius,
+                        ..focus_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        let handle = self.handle_icon(status, renderer).map(|icon| {
+            (
+                icon.font,
+                icon.code_point,
+                icon.size,
+                icon.line_height,
+                icon.shaping,
+            )
+        });
+
+        let handle_width = handle
+            .as_ref()
+            .map(|(_, _, size, _, _)| {
+                size.unwrap_or_else(|| renderer.default_size()).0
+                    + self.handle_spacing
+            })
+            .unwrap_or(0.0);
 
         if let Some((font, code_point, size, line_height, shaping)) = handle {
             let size = size.unwrap_or_else(|| renderer.default_size());
 
+            // The handle is the only content of an icon-only field
+            let (horizontal_alignment, x) = if self.icon_only {
+                (alignment::Horizontal::Center, bounds.center_x())
+            } else {
+                (
+                    alignment::Horizontal::Right,
+                    bounds.x + bounds.width
+                        - self.padding.right
+                        - self.handle_spacing,
+                )
+            };
+
             renderer.fill_text(
                 Text {
                     content: code_point.to_string(),
@@ -717,34 +2792,113 @@ where
                         bounds.width,
                         f32::from(line_height.to_absolute(size)),
                     ),
-                    horizontal_alignment: alignment::Horizontal::Right,
+                    horizontal_alignment,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping,
                     wrapping: text::Wrapping::default(),
                 },
+                Point::new(x, bounds.center_y()),
+                style.handle_color,
+                *viewport,
+            );
+        }
+
+        if self.icon_only {
+            return;
+        }
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let suffix = self
+            .suffix
+            .as_ref()
+            .and_then(|suffix| suffix(selected))
+            .filter(|suffix| !suffix.is_empty());
+
+        let suffix_width = if let Some(suffix) = suffix {
+            let paragraph = Renderer::Paragraph::with_text(Text {
+                content: &suffix,
+                size: text_size,
+                line_height: self.text_line_height,
+                font,
+                bounds: Size::new(
+                    f32::INFINITY,
+                    f32::from(self.text_line_height.to_absolute(text_size)),
+                ),
+                horizontal_alignment: alignment::Horizontal::Right,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: self.text_shaping,
+                wrapping: text::Wrapping::None,
+            });
+
+            renderer.fill_paragraph(
+                &paragraph,
                 Point::new(
-                    bounds.x + bounds.width - self.padding.right,
+                    bounds.x + bounds.width
+                        - self.padding.right
+                        - handle_width
+                        - SUFFIX_SPACING,
                     bounds.center_y(),
                 ),
-                style.handle_color,
+                style.suffix_color,
                 *viewport,
             );
-        }
+
+            suffix_width(paragraph.min_width())
+        } else {
+            0.0
+        };
 
         let label = selected.map(ToString::to_string);
 
         if let Some(label) = label.or_else(|| self.placeholder.clone()) {
-            let text_size =
-                self.text_size.unwrap_or_else(|| renderer.default_size());
-
             // Get the index of the selected item to check if it's disabled
-            let selected_index = selected.and_then(|selected| {
-                options.iter().position(|option| option == selected)
-            });
+            let selected_index = self.selected_index();
+
+            // The label is measured in layout with the font of its option
+            let label_font = self.label_font(selected, renderer);
+
+            let is_badge = is_selected && self.selected_badge;
+            let badge_padding = if is_badge { BADGE_PADDING } else { 0.0 };
+
+            let icon_width = if is_selected {
+                0.0
+            } else {
+                self.placeholder_icon_width(renderer)
+            };
+
+            if let Some(icon) =
+                self.placeholder_icon.as_ref().filter(|_| !is_selected)
+            {
+                let size = icon.size.unwrap_or_else(|| renderer.default_size());
+
+                renderer.fill_text(
+                    Text {
+                        content: icon.code_point.to_string(),
+                        size,
+                        line_height: icon.line_height,
+                        font: icon.font,
+                        bounds: Size::new(
+                            bounds.width,
+                            f32::from(icon.line_height.to_absolute(size)),
+                        ),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: icon.shaping,
+                        wrapping: text::Wrapping::default(),
+                    },
+                    Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                    style.placeholder_color,
+                    *viewport,
+                );
+            }
 
             let text_color = if is_selected {
-                if selected_index.map_or(false, |i| disabled_options[i]) {
+                if selected_index.is_some_and(|i| disabled_options[i]) {
                     style.disabled_text_color
+                } else if is_badge {
+                    style.badge_text_color
                 } else {
                     style.text_color
                 }
@@ -752,25 +2906,148 @@ where
                 style.placeholder_color
             };
 
-            renderer.fill_text(
-                Text {
-                    content: label,
+            let label_space = bounds.width
+                - self.padding.horizontal()
+                - handle_width
+                - suffix_width
+                - badge_padding * 2.0
+                - icon_width;
+
+            // The full label scrolls while the marquee is running
+            let is_scrolling =
+                self.marquee_on_hover && state.marquee_started_at.is_some();
+
+            let label = if self.truncate && !is_scrolling {
+                truncate::<Renderer::Paragraph>(
+                    Text {
+                        content: &label,
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font: label_font,
+                        bounds: Size::new(
+                            f32::INFINITY,
+                            f32::from(
+                                self.text_line_height.to_absolute(text_size),
+                            ),
+                        ),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: text::Wrapping::None,
+                    },
+                    label_space,
+                )
+                .unwrap_or(label)
+            } else {
+                label
+            };
+
+            if is_badge {
+                let line_height =
+                    f32::from(self.text_line_height.to_absolute(text_size));
+                let label_width = Renderer::Paragraph::with_text(Text {
+                    content: &label,
                     size: text_size,
                     line_height: self.text_line_height,
-                    font,
-                    bounds: Size::new(
-                        bounds.width - self.padding.horizontal(),
-                        f32::from(self.text_line_height.to_absolute(text_size)),
-                    ),
+                    font: label_font,
+                    bounds: Size::new(f32::INFINITY, line_height),
                     horizontal_alignment: alignment::Horizontal::Left,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
-                    wrapping: text::Wrapping::default(),
-                },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                text_color,
-                *viewport,
-            );
+                    wrapping: text::Wrapping::None,
+                })
+                .min_width()
+                .min(label_space);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + self.padding.left,
+                            y: bounds.center_y() - line_height / 2.0,
+                            width: label_width + BADGE_PADDING * 2.0,
+                            height: line_height,
+                        },
+                        border: border::rounded(line_height / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.badge_background,
+                );
+            }
+
+            if is_scrolling {
+                let line_height =
+                    f32::from(self.text_line_height.to_absolute(text_size));
+                let paragraph = Renderer::Paragraph::with_text(Text {
+                    content: label.as_str(),
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font: label_font,
+                    bounds: Size::new(f32::INFINITY, line_height),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: self.text_shaping,
+                    wrapping: text::Wrapping::None,
+                });
+
+                let period = paragraph.min_width() + MARQUEE_GAP;
+                let x =
+                    bounds.x + self.padding.left + badge_padding + icon_width
+                        - state.marquee_offset % period;
+
+                let clip = Rectangle {
+                    x: bounds.x
+                        + self.padding.left
+                        + badge_padding
+                        + icon_width,
+                    y: bounds.y,
+                    width: label_space,
+                    height: bounds.height,
+                };
+
+                renderer.with_layer(clip, |renderer| {
+                    // A second copy follows the first one, for a seamless loop
+                    for x in [x, x + period] {
+                        renderer.fill_paragraph(
+                            &paragraph,
+                            Point::new(x, bounds.center_y()),
+                            text_color,
+                            clip,
+                        );
+                    }
+                });
+            } else {
+                renderer.fill_text(
+                    Text {
+                        content: label,
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font: label_font,
+                        bounds: Size::new(
+                            bounds.width - self.padding.horizontal(),
+                            f32::from(
+                                self.text_line_height.to_absolute(text_size),
+                            ),
+                        ),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: if self.truncate {
+                            text::Wrapping::None
+                        } else {
+                            self.text_wrapping.unwrap_or_default()
+                        },
+                    },
+                    Point::new(
+                        bounds.x
+                            + self.padding.left
+                            + badge_padding
+                            + icon_width,
+                        bounds.center_y(),
+                    ),
+                    text_color,
+                    *viewport,
+                );
+            }
         }
     }
 
@@ -782,37 +3059,14 @@ where
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
-        let font = self.font.unwrap_or_else(|| renderer.default_font());
 
         if state.is_open {
             let bounds = layout.bounds();
-            let options = self.options.borrow();
-            let disabled = self.disabled.as_ref().map(|f| f(options));
-
-            let on_select = &self.on_select;
 
-            let mut menu = Menu::new(
-                &mut state.menu,
-                options,
-                &mut state.hovered_option,
-                |option| {
-                    state.is_open = false;
-                    (on_select)(option)
-                },
-                disabled,
-                None,
-                &self.menu_class,
+            Some(
+                self.menu(state, bounds.width, renderer)
+                    .overlay(layout.position() + translation, bounds.height),
             )
-            .width(bounds.width)
-            .padding(self.padding)
-            .font(font)
-            .text_shaping(self.text_shaping);
-
-            if let Some(text_size) = self.text_size {
-                menu = menu.text_size(text_size);
-            }
-
-            Some(menu.overlay(layout.position() + translation, bounds.height))
         } else {
             None
         }
@@ -837,33 +3091,468 @@ where
     }
 }
 
+/// The space between the label of a [`PickList`] and its suffix.
+const SUFFIX_SPACING: f32 = 5.0;
+
+/// The space between the placeholder icon of a [`PickList`] and its
+/// placeholder.
+const PLACEHOLDER_ICON_SPACING: f32 = 6.0;
+
+/// Returns the horizontal space taken by a suffix of the given width,
+/// including its spacing.
+fn suffix_width(width: f32) -> f32 {
+    if width > 0.0 {
+        width + SUFFIX_SPACING
+    } else {
+        0.0
+    }
+}
+
+/// Truncates the content of the given [`Text`] so that it fits within
+/// `max_width`, ending it with an ellipsis.
+///
+/// Returns `None` if the content already fits.
+fn truncate<P: text::Paragraph>(
+    text: Text<&str, P::Font>,
+    max_width: f32,
+) -> Option<String> {
+    const ELLIPSIS: char = '…';
+
+    if P::with_text(text).min_width() <= max_width {
+        return None;
+    }
+
+    let chars: Vec<char> = text.content.chars().collect();
+    let fits = |count: usize| {
+        let candidate: String =
+            chars[..count].iter().chain(Some(&ELLIPSIS)).collect();
+        let width = P::with_text(Text {
+            content: &candidate,
+            ..text
+        })
+        .min_width();
+
+        (width <= max_width).then_some(candidate)
+    };
+
+    // Binary search for the longest prefix that still fits
+    let (mut low, mut high) = (0, chars.len());
+    let mut best = fits(0).unwrap_or_else(|| ELLIPSIS.to_string());
+
+    while low < high {
+        let middle = (low + high).div_ceil(2);
+
+        if let Some(candidate) = fits(middle) {
+            best = candidate;
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    Some(best)
+}
+
+/// The measured text of an option of a [`PickList`].
+#[derive(Debug, Default)]
+struct OptionParagraphs<P: text::Paragraph> {
+    label: paragraph::Plain<P>,
+    description: paragraph::Plain<P>,
+    suffix: paragraph::Plain<P>,
+    shortcut: paragraph::Plain<P>,
+}
+
+impl<P: text::Paragraph> OptionParagraphs<P> {
+    /// Returns the width needed to fit the option, both in the field and in
+    /// its row of the [`Menu`].
+    fn width(&self) -> f32 {
+        let shortcut_width = self.shortcut.min_width();
+        let shortcut_width = if shortcut_width > 0.0 {
+            shortcut_width + menu::SHORTCUT_SPACING
+        } else {
+            0.0
+        };
+
+        (self.label.min_width() + suffix_width(self.suffix.min_width()))
+            .max(self.label.min_width() + shortcut_width)
+            .max(self.description.min_width())
+    }
+}
+
 #[derive(Debug)]
 struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
+    is_focus_visible: bool,
+    is_initialized: bool,
+    is_pending_open: bool,
+    pending_options_len: Option<usize>,
+    is_clipped: bool,
+    is_pending_clip: bool,
     hovered_option: Option<usize>,
-    options: Vec<paragraph::Plain<P>>,
+    is_hovered: bool,
+    is_opened_on_hover: bool,
+    hovered_at: Option<Instant>,
+    left_at: Option<Instant>,
+    marquee_started_at: Option<Instant>,
+    marquee_offset: f32,
+    typed: String,
+    typed_at: Option<Instant>,
+    scroll_accumulation: f32,
+    momentum: Option<Momentum>,
+    options: Vec<OptionParagraphs<P>>,
     placeholder: paragraph::Plain<P>,
+    placeholder_suffix: paragraph::Plain<P>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    /// Creates a new [`State`] for a [`PickList`].
+    fn new() -> Self {
+        Self {
+            menu: menu::State::default(),
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            is_open: bool::default(),
+            is_focused: bool::default(),
+            is_focus_visible: bool::default(),
+            is_initialized: bool::default(),
+            is_pending_open: bool::default(),
+            pending_options_len: None,
+            is_clipped: false,
+            is_pending_clip: false,
+            hovered_option: Option::default(),
+            is_hovered: bool::default(),
+            is_opened_on_hover: bool::default(),
+            hovered_at: None,
+            left_at: None,
+            marquee_started_at: None,
+            marquee_offset: 0.0,
+            typed: String::new(),
+            typed_at: None,
+            scroll_accumulation: 0.0,
+            momentum: None,
+            options: Vec::new(),
+            placeholder: paragraph::Plain::default(),
+            placeholder_suffix: paragraph::Plain::default(),
+        }
+    }
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        // Focus is usually moved with the keyboard
+        self.is_focused = true;
+        self.is_focus_visible = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The momentum of the selection of a [`PickList`] after a quick scroll.
+#[derive(Debug, Clone, Copy)]
+struct Momentum {
+    /// The velocity of the scroll, in lines per second.
+    velocity: f32,
+    scrolled_at: Instant,
+    ticked_at: Option<Instant>,
+    accumulation: f32,
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Produces an [`Operation`] that scrolls the open [`Menu`] of the
+/// [`PickList`] with the given [`Id`] to the option at the given index.
+///
+/// Nothing happens if the [`PickList`] is closed.
+pub fn scroll_to<T>(id: Id, index: usize) -> impl Operation<T> {
+    struct ScrollTo {
+        target: Id,
+        index: usize,
+    }
+
+    impl<T> Operation<T> for ScrollTo {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                if let Some(menu) = state.downcast_mut::<menu::State>() {
+                    menu.scroll_to(self.index);
+                }
+            }
+        }
+    }
+
+    ScrollTo { target: id, index }
+}
+
+/// The index of the hovered option in the open [`Menu`] of a [`PickList`].
+///
+/// It is exposed to widget operations as custom state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoveredOption(pub usize);
+
+/// Produces an [`Operation`] that returns the index of the hovered option in
+/// the open [`Menu`] of the [`PickList`] with the given [`Id`].
+///
+/// It returns `None` when the [`PickList`] is closed or nothing is hovered.
+/// The hovered option may be disabled. Run it with
+/// [`operate`](iced::advanced::widget::operate) to get the index in a
+/// [`Task`](iced::Task).
+pub fn hovered_option(id: Id) -> impl Operation<Option<usize>> {
+    struct Hovered {
+        target: Id,
+        index: Option<usize>,
+    }
+
+    impl Operation<Option<usize>> for Hovered {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Option<usize>>,
+            ),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                if let Some(HoveredOption(index)) =
+                    state.downcast_ref::<HoveredOption>()
+                {
+                    self.index = Some(*index);
+                }
+            }
+        }
+
+        fn finish(&self) -> operation::Outcome<Option<usize>> {
+            operation::Outcome::Some(self.index)
+        }
+    }
+
+    Hovered {
+        target: id,
+        index: None,
+    }
+}
+
+/// The height of the [`Menu`] of a [`PickList`] with all of its options
+/// visible, excluding its border.
+///
+/// It is exposed to widget operations as custom state once the [`Menu`] has
+/// been laid out or measured by the [`content_height`] operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContentHeight(pub f32);
+
+/// Whether the [`Menu`] of a [`PickList`] should be measured because its
+/// height is asked for, exposed to widget operations as custom state.
+struct MeasureContentHeight(bool);
+
+/// Produces an [`Operation`] that returns the height the [`Menu`] of the
+/// [`PickList`] with the given [`Id`] would take with all of its options
+/// visible, e.g. to choose between a dropdown and a modal.
+///
+/// The height includes every row of the [`Menu`], like its header, dividers
+/// and close button, as well as the descriptions and wrapped lines of the
+/// options, but not its border, which depends on its style. It returns `None`
+/// if no [`PickList`] has the given [`Id`]. Run it with
+/// [`operate`](iced::advanced::widget::operate) to get the height in a
+/// [`Task`](iced::Task).
+pub fn content_height(id: Id) -> impl Operation<Option<f32>> {
+    struct Height {
+        target: Id,
+        height: Option<f32>,
+    }
+
+    impl Operation<Option<f32>> for Height {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Option<f32>>,
+            ),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                if let Some(MeasureContentHeight(measure)) =
+                    state.downcast_mut::<MeasureContentHeight>()
+                {
+                    *measure = true;
+                } else if let Some(ContentHeight(height)) =
+                    state.downcast_ref::<ContentHeight>()
+                {
+                    self.height = Some(*height);
+                }
+            }
+        }
+
+        fn finish(&self) -> operation::Outcome<Option<f32>> {
+            operation::Outcome::Some(self.height)
+        }
+    }
+
+    Height {
+        target: id,
+        height: None,
+    }
+}
+
+/// Whether the [`Menu`] of a [`PickList`] is open, exposed to widget
+/// operations as custom state.
+struct Open(bool);
+
+/// The index of the option to hover in the open [`Menu`] of a [`PickList`],
+/// exposed to widget operations as custom state.
+struct Hover(Option<usize>);
+
+/// Produces an [`Operation`] that hovers the option at the given index in
+/// the open [`Menu`] of the [`PickList`] with the given [`Id`], e.g. to
+/// highlight it during a walkthrough.
+///
+/// An index past the last option hovers the last one, and a disabled option
+/// hovers the nearest enabled one, preferring the following ones. Nothing
+/// happens if the [`PickList`] is closed.
+pub fn set_hovered<T>(id: Id, index: usize) -> impl Operation<T> {
+    struct SetHovered {
+        target: Id,
+        index: usize,
+    }
+
+    impl<T> Operation<T> for SetHovered {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                if let Some(Hover(index)) = state.downcast_mut::<Hover>() {
+                    *index = Some(self.index);
+                }
+            }
+        }
+    }
+
+    SetHovered { target: id, index }
+}
+
+/// Produces an [`Operation`] that closes the open [`Menu`] of every
+/// [`PickList`], e.g. before showing a modal.
+///
+/// The [`PickList`]s are closed without producing their
+/// [`on_close`](PickList::on_close) message, since operations cannot
+/// publish messages.
+pub fn close_all<T>() -> impl Operation<T> {
+    struct CloseAll;
+
+    impl<T> Operation<T> for CloseAll {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+            if let Some(Open(is_open)) = state.downcast_mut::<Open>() {
+                *is_open = false;
+            }
+        }
+    }
+
+    CloseAll
+}
+
+/// An end of the options of a [`PickList`], reached while navigating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    /// The first enabled option.
+    First,
+    /// The last enabled option.
+    Last,
 }
 
-impl<P: text::Paragraph> State<P> {
-    /// Creates a new [`State`] for a [`PickList`].
-    fn new() -> Self {
-        Self {
-            menu: menu::State::default(),
-            keyboard_modifiers: keyboard::Modifiers::default(),
-            is_open: bool::default(),
-            hovered_option: Option::default(),
-            options: Vec::new(),
-            placeholder: paragraph::Plain::default(),
+/// What happens when the selected option of a [`PickList`] is picked again
+/// in its [`Menu`].
+///
+/// The [`Menu`] closes in every case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReselectBehavior<Message> {
+    /// The selection message is produced again.
+    Reemit,
+    /// No message is produced.
+    Ignore,
+    /// The given message is produced instead, e.g. to clear the selection.
+    Clear(Message),
+}
+
+/// The accessible name of a [`PickList`].
+///
+/// It is exposed to widget operations as custom state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Name(pub String);
+
+/// Produces an [`Operation`] that checks whether a [`PickList`] with the
+/// given [`Name`] exists.
+pub fn exists(name: impl Into<String>) -> impl Operation<bool> {
+    struct Exists {
+        name: String,
+        found: bool,
+    }
+
+    impl Operation<bool> for Exists {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<bool>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+            if let Some(Name(name)) = state.downcast_ref::<Name>() {
+                self.found |= *name == self.name;
+            }
+        }
+
+        fn finish(&self) -> operation::Outcome<bool> {
+            operation::Outcome::Some(self.found)
         }
     }
-}
 
-impl<P: text::Paragraph> Default for State<P> {
-    fn default() -> Self {
-        Self::new()
+    Exists {
+        name: name.into(),
+        found: false,
     }
 }
 
@@ -933,10 +3622,28 @@ pub struct Style {
     pub placeholder_color: Color,
     /// The handle [`Color`] of the pick list.
     pub handle_color: Color,
+    /// The suffix [`Color`] of the pick list.
+    pub suffix_color: Color,
+    /// The [`Background`] of the selected option displayed as a badge.
+    pub badge_background: Background,
+    /// The text [`Color`] of the selected option displayed as a badge.
+    pub badge_text_color: Color,
     /// The [`Background`] of the pick list.
     pub background: Background,
+    /// The [`Background`] of the pick list while hovered, replacing the base
+    /// one if any.
+    pub hovered_background: Option<Background>,
     /// The [`Border`] of the pick list.
     pub border: Border,
+    /// The [`Shadow`] cast by the pick list, if any.
+    pub shadow: Option<Shadow>,
+    /// The [`Border`] drawn over the base one while the closed pick list is
+    /// focused with the keyboard, if any.
+    ///
+    /// It is hidden once the pick list is pressed with the mouse or a touch.
+    ///
+    /// Its radius is always the one of the base [`Border`].
+    pub focus_border: Option<Border>,
 }
 
 /// The theme catalog of a [`PickList`].
@@ -985,13 +3692,23 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         text_color: palette.background.base.text,
         disabled_text_color: palette.background.weak.text,
         background: palette.background.weak.color.into(),
+        hovered_background: None,
         placeholder_color: palette.background.strong.color,
         handle_color: palette.background.weak.text,
+        suffix_color: palette.background.weak.text.scale_alpha(0.7),
+        badge_background: palette.primary.weak.color.into(),
+        badge_text_color: palette.primary.weak.text,
         border: Border {
             radius: 2.0.into(),
             width: 1.0,
             color: palette.background.strong.color,
         },
+        shadow: None,
+        focus_border: Some(Border {
+            width: 2.0,
+            color: palette.primary.base.color,
+            ..Border::default()
+        }),
     };
 
     match status {
@@ -1013,3 +3730,764 @@ pub const DEFAULT_PADDING: Padding = Padding {
     right: 10.0,
     left: 10.0,
 };
+
+/// The code point of the arrow shown by an open [`Handle::Arrow`] that flips.
+const ARROW_UP_ICON: char = '▲';
+
+/// The code point of the arrow shown by a closed [`Handle::Arrow`] that flips.
+const ARROW_DOWN_ICON: char = '▼';
+
+/// The horizontal space between the label of a badge and its edges.
+const BADGE_PADDING: f32 = 6.0;
+
+/// How long the cursor can stay away from a [`PickList`] opened on hover, and
+/// its [`Menu`], before the [`Menu`] closes.
+const HOVER_CLOSE_GRACE: Duration = Duration::from_millis(300);
+
+/// The time a truncated label stays still before its marquee starts.
+const MARQUEE_DELAY: Duration = Duration::from_millis(500);
+
+/// The speed of the marquee of a truncated label, in pixels per second.
+const MARQUEE_SPEED: f32 = 40.0;
+
+/// The space between the end of a scrolling label and its next copy.
+const MARQUEE_GAP: f32 = 24.0;
+
+/// How long the wheel must stay still before the selection coasts on its
+/// momentum.
+const MOMENTUM_IDLE: Duration = Duration::from_millis(60);
+
+/// The rate at which the momentum of the selection decays, per second.
+const MOMENTUM_DECAY: f32 = 6.0;
+
+/// The velocity below which the momentum of the selection stops, in lines
+/// per second.
+const MOMENTUM_MIN_VELOCITY: f32 = 2.0;
+
+/// The highest velocity the momentum of the selection can reach, in lines
+/// per second.
+const MOMENTUM_MAX_VELOCITY: f32 = 40.0;
+
+/// How long a pause in typing must last for the next character to start a
+/// new type-ahead search.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Selected(&'static str),
+        Closed,
+        Cleared,
+    }
+
+    type TestPickList<'a> = PickList<
+        'a,
+        &'static str,
+        &'a [&'static str],
+        &'static str,
+        Message,
+        iced::Theme,
+        (),
+    >;
+
+    fn pick_list<'a>(
+        options: &'a [&'static str],
+        selected: Option<&'static str>,
+    ) -> TestPickList<'a> {
+        PickList::new(
+            options,
+            None::<fn(&[&'static str]) -> Vec<bool>>,
+            selected,
+            Message::Selected,
+        )
+    }
+
+    const FRUITS: &[&str] = &["Apple", "Avocado", "Banana", "Apricot"];
+
+    /// The bounds the [`PickList`] under test is laid out in.
+    const BOUNDS: Size = Size::new(200.0, 40.0);
+
+    fn tree(pick_list: &TestPickList<'_>) -> Tree {
+        Tree::new(pick_list as &dyn Widget<Message, iced::Theme, ()>)
+    }
+
+    fn state(tree: &Tree) -> &State<()> {
+        tree.state.downcast_ref::<State<()>>()
+    }
+
+    /// Lays out the [`PickList`] and sends it an event with the cursor at
+    /// the given position, returning its status and the produced messages.
+    fn send(
+        pick_list: &mut TestPickList<'_>,
+        tree: &mut Tree,
+        event: Event,
+        cursor: Option<Point>,
+    ) -> (event::Status, Vec<Message>) {
+        let node = pick_list.layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, BOUNDS),
+        );
+        let cursor = cursor.map_or(mouse::Cursor::Unavailable, |position| {
+            mouse::Cursor::Available(position)
+        });
+        let mut messages = Vec::new();
+
+        let status = pick_list.on_event(
+            tree,
+            event,
+            Layout::new(&node),
+            cursor,
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(BOUNDS),
+        );
+
+        (status, messages)
+    }
+
+    /// The size of the window the [`Menu`] opens in.
+    const WINDOW: Size = Size::new(800.0, 600.0);
+
+    /// Lays out the open [`Menu`] of the [`PickList`] and sends it an event
+    /// with the cursor over the given row, returning its status and the
+    /// produced messages.
+    fn send_to_menu(
+        pick_list: &mut TestPickList<'_>,
+        tree: &mut Tree,
+        event: Event,
+        row: Option<usize>,
+    ) -> (event::Status, Vec<Message>) {
+        let rows = pick_list.options.len() as f32;
+        let node = pick_list.layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, BOUNDS),
+        );
+        let mut menu = pick_list
+            .overlay(tree, Layout::new(&node), &(), Vector::ZERO)
+            .expect("the menu should be open");
+        let menu_node = menu.layout(&(), WINDOW);
+        let bounds = menu_node.bounds();
+
+        let cursor = row.map_or(mouse::Cursor::Unavailable, |row| {
+            mouse::Cursor::Available(Point::new(
+                bounds.x + 1.0,
+                bounds.y + bounds.height / rows * (row as f32 + 0.5),
+            ))
+        });
+        let mut messages = Vec::new();
+
+        let status = menu.on_event(
+            event,
+            Layout::new(&menu_node),
+            cursor,
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+        );
+
+        (status, messages)
+    }
+
+    /// Lays out the open [`Menu`] of the [`PickList`], returning the scroll
+    /// offset and the bounds of its list.
+    fn menu_scroll(
+        pick_list: &mut TestPickList<'_>,
+        tree: &mut Tree,
+    ) -> (Vector, Rectangle) {
+        struct Translation(Option<(Vector, Rectangle)>);
+
+        impl Operation for Translation {
+            fn container(
+                &mut self,
+                _id: Option<&Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+            ) {
+                operate_on_children(self);
+            }
+
+            fn scrollable(
+                &mut self,
+                _state: &mut dyn operation::Scrollable,
+                _id: Option<&Id>,
+                bounds: Rectangle,
+                _content_bounds: Rectangle,
+                translation: Vector,
+            ) {
+                self.0 = Some((translation, bounds));
+            }
+        }
+
+        let node = pick_list.layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, BOUNDS),
+        );
+        let mut menu = pick_list
+            .overlay(tree, Layout::new(&node), &(), Vector::ZERO)
+            .expect("the menu should be open");
+        let menu_node = menu.layout(&(), WINDOW);
+        let mut translation = Translation(None);
+
+        menu.operate(Layout::new(&menu_node), &(), &mut translation);
+
+        translation.0.expect("the menu should scroll")
+    }
+
+    fn click() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    fn cursor_moved() -> Event {
+        // The position of the cursor is given to the menu separately
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::ORIGIN,
+        })
+    }
+
+    fn key(key: keyboard::key::Named) -> Event {
+        key_press(keyboard::Key::Named(key))
+    }
+
+    fn character(character: char) -> Event {
+        key_press(keyboard::Key::Character(character.to_string().into()))
+    }
+
+    fn key_press(key: keyboard::Key) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key,
+            physical_key: keyboard::key::Physical::Unidentified(
+                keyboard::key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+        })
+    }
+
+    #[test]
+    fn tab_closes_the_menu_and_leaves_the_focus_to_the_focus_operation() {
+        let mut pick_list = pick_list(FRUITS, None).on_close(Message::Closed);
+        let mut tree = tree(&pick_list);
+
+        let _ = send(&mut pick_list, &mut tree, click(), Some(Point::ORIGIN));
+        assert!(state(&tree).is_open);
+
+        let (status, messages) = send(
+            &mut pick_list,
+            &mut tree,
+            key(keyboard::key::Named::Tab),
+            None,
+        );
+
+        assert_eq!(status, event::Status::Ignored);
+        assert_eq!(messages, vec![Message::Closed]);
+        assert!(!state(&tree).is_open);
+        assert!(operation::Focusable::is_focused(state(&tree)));
+    }
+
+    #[test]
+    fn upward_scroll_does_nothing_when_up_is_disabled() {
+        let mut pick_list =
+            pick_list(FRUITS, Some("Banana")).scroll_directions(false, true);
+        let mut tree = tree(&pick_list);
+
+        let scroll = |y| {
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y },
+            })
+        };
+
+        let _ = send(
+            &mut pick_list,
+            &mut tree,
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::COMMAND,
+            )),
+            None,
+        );
+
+        let (_, messages) =
+            send(&mut pick_list, &mut tree, scroll(1.0), Some(Point::ORIGIN));
+        assert!(messages.is_empty());
+
+        let (_, messages) =
+            send(&mut pick_list, &mut tree, scroll(-1.0), Some(Point::ORIGIN));
+        assert_eq!(messages, vec![Message::Selected("Apricot")]);
+    }
+
+    #[test]
+    fn pixel_scroll_without_steps_is_left_to_the_parents() {
+        let mut pick_list = pick_list(FRUITS, Some("Banana"));
+        let mut tree = tree(&pick_list);
+
+        let _ = send(
+            &mut pick_list,
+            &mut tree,
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::COMMAND,
+            )),
+            None,
+        );
+
+        let (status, messages) = send(
+            &mut pick_list,
+            &mut tree,
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 0.0, y: 10.0 },
+            }),
+            Some(Point::ORIGIN),
+        );
+
+        assert_eq!(status, event::Status::Ignored);
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn repeated_clicks_on_the_field_toggle_the_menu() {
+        let mut pick_list = pick_list(FRUITS, None).on_close(Message::Closed);
+        let mut tree = tree(&pick_list);
+
+        for is_open in [true, false, true, false] {
+            let (status, messages) =
+                send(&mut pick_list, &mut tree, click(), Some(Point::ORIGIN));
+
+            assert_eq!(status, event::Status::Captured);
+            assert_eq!(state(&tree).is_open, is_open);
+            assert_eq!(messages.is_empty(), is_open);
+        }
+    }
+
+    #[test]
+    fn cursor_over_a_disabled_option_hovers_it_without_selecting_it() {
+        let mut pick_list = PickList::new(
+            FRUITS,
+            Some(|options: &[&str]| {
+                options.iter().map(|option| *option == "Avocado").collect()
+            }),
+            None,
+            Message::Selected,
+        );
+        let mut tree = tree(&pick_list);
+
+        let _ = send(&mut pick_list, &mut tree, click(), Some(Point::ORIGIN));
+
+        let (_, messages) =
+            send_to_menu(&mut pick_list, &mut tree, cursor_moved(), Some(1));
+        assert!(messages.is_empty());
+        assert_eq!(state(&tree).hovered_option, Some(1));
+
+        // The press over the menu reaches the field without a cursor
+        let (_, menu_messages) =
+            send_to_menu(&mut pick_list, &mut tree, click(), Some(1));
+        let (_, messages) = send(&mut pick_list, &mut tree, click(), None);
+        assert!(menu_messages.is_empty() && messages.is_empty());
+        assert!(state(&tree).is_open);
+
+        let (_, messages) =
+            send_to_menu(&mut pick_list, &mut tree, cursor_moved(), Some(2));
+        assert!(messages.is_empty());
+        assert_eq!(state(&tree).hovered_option, Some(2));
+    }
+
+    #[test]
+    fn clicking_the_selected_option_follows_the_reselect_behavior() {
+        for (behavior, expected) in [
+            (ReselectBehavior::Reemit, vec![Message::Selected("Banana")]),
+            (ReselectBehavior::Ignore, vec![]),
+            (
+                ReselectBehavior::Clear(Message::Cleared),
+                vec![Message::Cleared],
+            ),
+        ] {
+            let mut pick_list =
+                pick_list(FRUITS, Some("Banana")).on_reselect(behavior);
+            let mut tree = tree(&pick_list);
+
+            let _ =
+                send(&mut pick_list, &mut tree, click(), Some(Point::ORIGIN));
+
+            let (_, messages) =
+                send_to_menu(&mut pick_list, &mut tree, click(), Some(2));
+
+            assert_eq!(messages, expected);
+            assert!(!state(&tree).is_open);
+
+            // Another option is still selected as usual
+            let _ =
+                send(&mut pick_list, &mut tree, click(), Some(Point::ORIGIN));
+
+            let (_, messages) =
+                send_to_menu(&mut pick_list, &mut tree, click(), Some(0));
+            assert_eq!(messages, vec![Message::Selected("Apple")]);
+        }
+    }
+
+    #[test]
+    fn menu_of_a_pick_list_in_a_small_scrollable_is_not_clipped() {
+        let mut element: Element<'_, Message, iced::Theme, ()> =
+            iced::widget::Scrollable::new(pick_list(FRUITS, None))
+                .height(50)
+                .into();
+        let mut tree = Tree::new(&element);
+        let limits = layout::Limits::new(Size::ZERO, WINDOW);
+
+        let node = element.as_widget().layout(&mut tree, &(), &limits);
+        let mut messages = Vec::new();
+
+        let _ = element.as_widget_mut().on_event(
+            &mut tree,
+            click(),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::new(1.0, 1.0)),
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(WINDOW),
+        );
+
+        let node = element.as_widget().layout(&mut tree, &(), &limits);
+        let field = node.children()[0].bounds();
+
+        let menu = element
+            .as_widget_mut()
+            .overlay(&mut tree, Layout::new(&node), &(), Vector::ZERO)
+            .expect("the menu should be open")
+            .layout(&(), WINDOW)
+            .bounds();
+
+        let content_height = state(&tree.children[0])
+            .menu
+            .content_height()
+            .expect("the menu should be laid out");
+
+        assert!(content_height > node.bounds().height);
+        assert_eq!(menu.height, content_height);
+        assert_eq!(menu.y, field.y + field.height);
+    }
+
+    /// A paragraph whose glyphs are wider in a monospaced font.
+    #[derive(Debug, Clone, Copy, Default)]
+    struct Measured {
+        font: iced::Font,
+        width: f32,
+    }
+
+    impl text::Paragraph for Measured {
+        type Font = iced::Font;
+
+        fn with_text(text: Text<&str>) -> Self {
+            let glyph_width = if text.font == iced::Font::MONOSPACE {
+                10.0
+            } else {
+                5.0
+            };
+
+            Self {
+                font: text.font,
+                width: text.content.chars().count() as f32 * glyph_width,
+            }
+        }
+
+        fn with_spans<Link>(
+            _text: Text<&[text::Span<'_, Link, Self::Font>], Self::Font>,
+        ) -> Self {
+            Self::default()
+        }
+
+        fn resize(&mut self, _new_bounds: Size) {}
+
+        fn compare(&self, _text: Text<()>) -> text::Difference {
+            text::Difference::Shape
+        }
+
+        fn horizontal_alignment(&self) -> alignment::Horizontal {
+            alignment::Horizontal::Left
+        }
+
+        fn vertical_alignment(&self) -> alignment::Vertical {
+            alignment::Vertical::Top
+        }
+
+        fn min_bounds(&self) -> Size {
+            Size::new(self.width, 20.0)
+        }
+
+        fn hit_test(&self, _point: Point) -> Option<text::Hit> {
+            None
+        }
+
+        fn hit_span(&self, _point: Point) -> Option<usize> {
+            None
+        }
+
+        fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
+            Vec::new()
+        }
+
+        fn grapheme_position(
+            &self,
+            _line: usize,
+            _index: usize,
+        ) -> Option<Point> {
+            None
+        }
+    }
+
+    /// A renderer measuring text with [`Measured`] paragraphs and recording
+    /// the font of the text it draws.
+    #[derive(Debug, Default)]
+    struct Recorder {
+        texts: Vec<(String, iced::Font)>,
+    }
+
+    impl renderer::Renderer for Recorder {
+        fn start_layer(&mut self, _bounds: Rectangle) {}
+
+        fn end_layer(&mut self) {}
+
+        fn start_transformation(
+            &mut self,
+            _transformation: iced::Transformation,
+        ) {
+        }
+
+        fn end_transformation(&mut self) {}
+
+        fn clear(&mut self) {}
+
+        fn fill_quad(
+            &mut self,
+            _quad: renderer::Quad,
+            _background: impl Into<Background>,
+        ) {
+        }
+    }
+
+    impl text::Renderer for Recorder {
+        type Font = iced::Font;
+        type Paragraph = Measured;
+        type Editor = ();
+
+        const ICON_FONT: iced::Font = iced::Font::DEFAULT;
+        const CHECKMARK_ICON: char = '0';
+        const ARROW_DOWN_ICON: char = '0';
+
+        fn default_font(&self) -> Self::Font {
+            iced::Font::default()
+        }
+
+        fn default_size(&self) -> Pixels {
+            Pixels(16.0)
+        }
+
+        fn fill_paragraph(
+            &mut self,
+            _paragraph: &Self::Paragraph,
+            _position: Point,
+            _color: Color,
+            _clip_bounds: Rectangle,
+        ) {
+        }
+
+        fn fill_editor(
+            &mut self,
+            _editor: &Self::Editor,
+            _position: Point,
+            _color: Color,
+            _clip_bounds: Rectangle,
+        ) {
+        }
+
+        fn fill_text(
+            &mut self,
+            text: Text,
+            _position: Point,
+            _color: Color,
+            _clip_bounds: Rectangle,
+        ) {
+            self.texts.push((text.content, text.font));
+        }
+    }
+
+    #[test]
+    fn label_is_drawn_in_the_font_it_is_measured_with() {
+        let icons = iced::Font::with_name("Icons");
+        let pick_list: PickList<'_, _, _, _, Message, iced::Theme, Recorder> =
+            PickList::new(
+                FRUITS,
+                None::<fn(&[&'static str]) -> Vec<bool>>,
+                Some("Banana"),
+                Message::Selected,
+            )
+            .font(iced::Font::MONOSPACE)
+            .option_font(move |option| (*option == "Banana").then_some(icons));
+
+        let mut tree = Tree::new(
+            &pick_list as &dyn Widget<Message, iced::Theme, Recorder>,
+        );
+        let mut renderer = Recorder::default();
+
+        let node = pick_list.layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, WINDOW),
+        );
+
+        let state = tree.state.downcast_ref::<State<Measured>>();
+        let fonts: Vec<_> = state
+            .options
+            .iter()
+            .map(|paragraphs| paragraphs.label.raw().font)
+            .collect();
+
+        assert_eq!(
+            fonts,
+            [
+                iced::Font::MONOSPACE,
+                iced::Font::MONOSPACE,
+                icons,
+                iced::Font::MONOSPACE
+            ]
+        );
+
+        // The widest label is "Avocado", in the wider monospaced font
+        assert!(node.bounds().width >= 70.0);
+
+        pick_list.draw(
+            &tree,
+            &mut renderer,
+            &iced::Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(WINDOW),
+        );
+
+        assert!(renderer.texts.contains(&(String::from("Banana"), icons)));
+    }
+
+    #[test]
+    fn arrows_scroll_the_hovered_option_into_view_as_little_as_possible() {
+        let options: Vec<&'static str> = (0..100)
+            .map(|i| &*Box::leak(format!("Option {i}").into_boxed_str()))
+            .collect();
+        let mut pick_list = pick_list(&options, None);
+        let mut tree = tree(&pick_list);
+
+        let _ = send(&mut pick_list, &mut tree, click(), Some(Point::ORIGIN));
+        let (offset, list) = menu_scroll(&mut pick_list, &mut tree);
+        assert_eq!(offset, Vector::ZERO);
+
+        let arrow_down = |pick_list: &mut TestPickList<'_>, tree: &mut Tree| {
+            let _ = send(
+                pick_list,
+                tree,
+                key(keyboard::key::Named::ArrowDown),
+                None,
+            );
+        };
+
+        // The hovered option is already visible
+        for _ in 0..3 {
+            arrow_down(&mut pick_list, &mut tree);
+        }
+        assert_eq!(menu_scroll(&mut pick_list, &mut tree).0, Vector::ZERO);
+
+        // The first hidden option is revealed at the bottom of the list
+        // rather than moved to its top
+        let mut offset = Vector::ZERO;
+        for _ in 0..options.len() {
+            arrow_down(&mut pick_list, &mut tree);
+            offset = menu_scroll(&mut pick_list, &mut tree).0;
+
+            if offset != Vector::ZERO {
+                break;
+            }
+        }
+        assert!(offset.y > 0.0 && offset.y < list.height / 2.0);
+    }
+
+    #[test]
+    fn type_ahead_keeps_the_scroll_offset_when_the_match_is_visible() {
+        let options: Vec<&'static str> = (0..100)
+            .map(|i| &*Box::leak(format!("Option {i}").into_boxed_str()))
+            .collect();
+        let mut pick_list = pick_list(&options, None);
+        let mut tree = tree(&pick_list);
+
+        let _ = send(&mut pick_list, &mut tree, click(), Some(Point::ORIGIN));
+        assert_eq!(menu_scroll(&mut pick_list, &mut tree).0, Vector::ZERO);
+
+        for c in "option 5".chars() {
+            let _ = send(&mut pick_list, &mut tree, character(c), None);
+        }
+
+        assert_eq!(state(&tree).hovered_option, Some(5));
+        assert_eq!(menu_scroll(&mut pick_list, &mut tree).0, Vector::ZERO);
+    }
+
+    #[test]
+    fn closed_menu_is_only_measured_for_its_content_height() {
+        let id = Id::new("fruits");
+        let pick_list = pick_list(FRUITS, None).id(id.clone());
+        let mut tree = tree(&pick_list);
+
+        let node = pick_list.layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, BOUNDS),
+        );
+
+        let mut count = operation::focusable::count();
+        pick_list.operate(
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            &mut operation::black_box(&mut count),
+        );
+        assert_eq!(state(&tree).menu.content_height(), None);
+
+        let mut height = content_height(id);
+        pick_list.operate(
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            &mut operation::black_box(&mut height),
+        );
+
+        let operation::Outcome::Some(Some(height)) = height.finish() else {
+            panic!("the content height should be measured");
+        };
+        assert!(height > 0.0);
+        assert_eq!(state(&tree).menu.content_height(), Some(height));
+    }
+
+    #[test]
+    fn type_ahead_cycles_through_options_with_a_repeated_letter() {
+        let pick_list = pick_list(FRUITS, None);
+
+        assert_eq!(pick_list.type_ahead_index("a", None), Some(0));
+        assert_eq!(pick_list.type_ahead_index("aa", Some(0)), Some(1));
+        assert_eq!(pick_list.type_ahead_index("aaa", Some(1)), Some(3));
+        assert_eq!(pick_list.type_ahead_index("aaaa", Some(3)), Some(0));
+    }
+
+    #[test]
+    fn type_ahead_narrows_options_with_a_prefix() {
+        let pick_list = pick_list(FRUITS, None);
+
+        assert_eq!(pick_list.type_ahead_index("ap", None), Some(0));
+        assert_eq!(pick_list.type_ahead_index("apr", Some(0)), Some(3));
+        assert_eq!(pick_list.type_ahead_index("b", Some(3)), Some(2));
+        assert_eq!(pick_list.type_ahead_index("c", None), None);
+    }
+}